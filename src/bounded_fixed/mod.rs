@@ -0,0 +1,481 @@
+/// Implemented by every type generated through [`bound_fixed_impl!`].
+pub trait BoundedFixed {
+	type Raw: Copy;
+
+	/// How many of the raw bits are fractional, a raw value of `1 << FRAC_BITS` is `1.0`.
+	const FRAC_BITS: u32;
+	const MIN_RAW: Self::Raw;
+	const MAX_RAW: Self::Raw;
+
+	fn new(value: f64) -> Self;
+	fn get(&self) -> f64;
+	fn from_raw(raw: Self::Raw) -> Self;
+	fn raw(&self) -> Self::Raw;
+}
+
+/// Implements a bounded fixed-point number for a struct with a single private field `inner_value` of the given
+/// primitive integer type, the last `$frac` bits of which are fractional.
+///
+/// Arithmetic between two bounded values is done on the raw integers, widened to `i128`, then clamped into range,
+/// so results are identical on every machine. Division by zero saturates towards the sign of the dividend,
+/// remainder by zero returns the dividend, as with the bounded integers. Multiplication rounds towards negative infinity, division towards zero.
+///
+/// Floats are only involved when converting from and to them, out of range values are clamped and NaN becomes `MIN`.
+/// Comparisons with `f64` are exact, without converting the raw integer to a float.
+///
+/// Fails to compile, once the type is used, if the raw integer has more than 64 bits, `$frac` is not below 64,
+/// either bound does not fit into the raw integer, or `MIN > MAX`.
+#[macro_export]
+macro_rules! bound_fixed_impl {
+	($struct_name: ty: $int: ident, $frac: expr, $min: expr, $max: expr $(,)?) => {
+		impl $crate::prelude::BoundedFixed for $struct_name {
+			type Raw = $int;
+
+			const FRAC_BITS: u32 = $frac;
+			const MIN_RAW: $int = Self::MIN.inner_value;
+			const MAX_RAW: $int = Self::MAX.inner_value;
+
+			fn new(value: f64) -> Self {
+				return Self::new(value);
+			}
+
+			fn get(&self) -> f64 {
+				return self.get();
+			}
+
+			fn from_raw(raw: $int) -> Self {
+				return Self::from_raw(raw);
+			}
+
+			fn raw(&self) -> $int {
+				return self.inner_value;
+			}
+		}
+
+		#[allow(dead_code)]
+		impl $struct_name {
+			pub const FRAC_BITS: u32 = $frac;
+			pub const MIN: Self = {
+				let (min, max) = (Self::round_raw($min), Self::round_raw($max));
+				assert!(<$int>::BITS <= 64, "bounded fixed-point requires a raw integer of at most 64 bits");
+				assert!(Self::FRAC_BITS < 64, "bounded fixed-point requires fewer than 64 fractional bits");
+				assert!(
+					min >= <$int>::MIN as i128 && max <= <$int>::MAX as i128,
+					"bounded fixed-point requires both bounds to fit into the raw integer"
+				);
+				assert!(min <= max, "bounded fixed-point requires `MIN <= MAX`");
+				Self { inner_value: min as $int }
+			};
+			pub const MAX: Self = {
+				let _ = Self::MIN;
+				Self { inner_value: Self::round_raw($max) as $int }
+			};
+
+			const ONE: i128 = 1 << Self::FRAC_BITS;
+
+			/// Rounds `value` to the nearest fixed-point value, then clamps it into range. NaN becomes `MIN`.
+			pub fn new(value: f64) -> Self {
+				if value.is_nan() {
+					return Self::MIN;
+				}
+
+				return Self::clamped(Self::round_raw(value));
+			}
+
+			/// Like [`Self::new`], but fails instead of clamping.
+			pub fn try_new(value: f64) -> Result<Self, $crate::prelude::BoundError> {
+				let raw = Self::round_raw(value);
+				return if value.is_nan() {
+					Err($crate::prelude::BoundError::NaN)
+				} else if raw < Self::MIN.inner_value as i128 {
+					Err($crate::prelude::BoundError::BelowMin { value, min: Self::MIN.get() })
+				} else if raw > Self::MAX.inner_value as i128 {
+					Err($crate::prelude::BoundError::AboveMax { value, max: Self::MAX.get() })
+				} else {
+					Ok(Self { inner_value: raw as $int })
+				};
+			}
+
+			/// Clamps the raw representation into range.
+			pub fn from_raw(raw: $int) -> Self {
+				return Self::clamped(raw as i128);
+			}
+
+			pub const fn raw(&self) -> $int {
+				return self.inner_value;
+			}
+
+			pub fn get(&self) -> f64 {
+				return self.inner_value as f64 / Self::ONE as f64;
+			}
+
+			pub fn to_f32(self) -> f32 {
+				return self.get() as f32;
+			}
+
+			/// Clamps `value` into range, see [`Self::new`].
+			pub fn set(&mut self, value: f64) {
+				*self = Self::new(value);
+			}
+
+			/// Clamps the raw representation into range.
+			pub fn set_raw(&mut self, raw: $int) {
+				*self = Self::from_raw(raw);
+			}
+
+			const fn round_raw(value: f64) -> i128 {
+				return (value * (1i128 << $frac) as f64).round() as i128;
+			}
+
+			fn clamped(raw: i128) -> Self {
+				let raw = raw.clamp(Self::MIN.inner_value as i128, Self::MAX.inner_value as i128);
+				return Self { inner_value: raw as $int };
+			}
+
+			fn fixed_add(lhs: i128, rhs: i128) -> i128 {
+				return lhs + rhs;
+			}
+
+			fn fixed_sub(lhs: i128, rhs: i128) -> i128 {
+				return lhs - rhs;
+			}
+
+			/// Raw values fit into 64 bits, with fewer than 64 fractional bits,
+			/// so a product that overflows `i128` is out of range anyway and saturates.
+			fn fixed_mul(lhs: i128, rhs: i128) -> i128 {
+				return match lhs.checked_mul(rhs) {
+					Some(product) => product >> Self::FRAC_BITS,
+					None => Self::saturated(lhs.signum() * rhs.signum()),
+				};
+			}
+
+			/// Shifts the quotient and the remainder separately, so that `lhs << FRAC_BITS` cannot overflow `i128`.
+			fn fixed_div(lhs: i128, rhs: i128) -> i128 {
+				if rhs == 0 {
+					return Self::saturated(lhs.signum());
+				}
+
+				let (quotient, remainder) = (lhs / rhs, lhs % rhs);
+				return match quotient.checked_mul(Self::ONE) {
+					Some(high) => high + (remainder << Self::FRAC_BITS) / rhs,
+					None => Self::saturated(lhs.signum() * rhs.signum()),
+				};
+			}
+
+			fn saturated(signum: i128) -> i128 {
+				return signum * i128::MAX;
+			}
+
+			fn fixed_rem(lhs: i128, rhs: i128) -> i128 {
+				return match rhs {
+					0 => lhs,
+					_ => lhs % rhs,
+				};
+			}
+		}
+
+		$crate::__bound_fixed_operator!($struct_name; Add, add, AddAssign, add_assign, fixed_add);
+		$crate::__bound_fixed_operator!($struct_name; Sub, sub, SubAssign, sub_assign, fixed_sub);
+		$crate::__bound_fixed_operator!($struct_name; Mul, mul, MulAssign, mul_assign, fixed_mul);
+		$crate::__bound_fixed_operator!($struct_name; Div, div, DivAssign, div_assign, fixed_div);
+		$crate::__bound_fixed_operator!(
+			/// The remainder by zero is the dividend, as with the bounded integers.
+			$struct_name; Rem, rem, RemAssign, rem_assign, fixed_rem
+		);
+
+		impl std::ops::Neg for $struct_name {
+			type Output = Self;
+
+			fn neg(self) -> Self::Output {
+				return Self::clamped(-(self.inner_value as i128));
+			}
+		}
+
+		impl core::cmp::PartialEq for $struct_name {
+			fn eq(&self, other: &Self) -> bool {
+				return self.inner_value == other.inner_value;
+			}
+		}
+
+		impl core::cmp::Eq for $struct_name {}
+
+		impl core::hash::Hash for $struct_name {
+			fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+				core::hash::Hash::hash(&self.inner_value, state);
+			}
+		}
+
+		impl core::cmp::PartialOrd for $struct_name {
+			fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+				return Some(core::cmp::Ord::cmp(self, other));
+			}
+		}
+
+		impl core::cmp::Ord for $struct_name {
+			fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+				return self.inner_value.cmp(&other.inner_value);
+			}
+		}
+
+		/// Exact, see the [`PartialOrd<f64>`](core::cmp::PartialOrd) implementation.
+		impl core::cmp::PartialEq<f64> for $struct_name {
+			fn eq(&self, other: &f64) -> bool {
+				return self.partial_cmp(other) == Some(std::cmp::Ordering::Equal);
+			}
+		}
+
+		/// Exact: `other` is scaled by `2^FRAC_BITS`, which no float rounding can affect,
+		/// then compared against the raw integer. [`Self::get`] would round raw values of more than 53 bits.
+		impl core::cmp::PartialOrd<f64> for $struct_name {
+			fn partial_cmp(&self, other: &f64) -> Option<std::cmp::Ordering> {
+				if other.is_nan() {
+					return None;
+				}
+
+				let scaled = *other * Self::ONE as f64;
+				let floor = scaled.floor();
+				return Some(if floor >= i128::MAX as f64 {
+					std::cmp::Ordering::Less
+				} else if floor < i128::MIN as f64 {
+					std::cmp::Ordering::Greater
+				} else {
+					let raw = self.inner_value as i128;
+					raw.cmp(&(floor as i128)).then(if scaled > floor {
+						std::cmp::Ordering::Less
+					} else {
+						std::cmp::Ordering::Equal
+					})
+				});
+			}
+		}
+
+		impl core::convert::From<f64> for $struct_name {
+			fn from(value: f64) -> Self {
+				return Self::new(value);
+			}
+		}
+
+		impl core::convert::From<f32> for $struct_name {
+			fn from(value: f32) -> Self {
+				return Self::new(value as f64);
+			}
+		}
+
+		impl core::convert::From<$struct_name> for f64 {
+			fn from(value: $struct_name) -> Self {
+				return value.get();
+			}
+		}
+
+		impl core::convert::From<$struct_name> for f32 {
+			fn from(value: $struct_name) -> Self {
+				return value.to_f32();
+			}
+		}
+
+		impl core::default::Default for $struct_name {
+			fn default() -> Self {
+				return Self::MIN;
+			}
+		}
+
+		impl std::fmt::Display for $struct_name {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				return $crate::prelude::fmt_bounded(self.get(), "", None, f);
+			}
+		}
+	};
+}
+
+/// Declares one or more bounded fixed-point newtypes and implements [`bound_fixed_impl!`] for each of them.
+///
+/// Each type names its raw integer, how many of its bits are fractional, and its range.
+/// Attributes (including doc comments) and visibility are forwarded to the generated struct,
+/// which always derives `Debug`, `Clone` and `Copy`.
+///
+/// ```
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_fixed! {
+///     /// Health with 16 fractional bits, for lockstep simulation.
+///     pub struct Health(i32, 16, 0.0..=100.0);
+/// }
+///
+/// let health = Health::new(40.0) * Health::new(1.5);
+/// assert_eq!(health, 60.0);
+/// assert_eq!(health + Health::new(50.0), Health::MAX);
+/// assert_eq!(Health::new(0.25).raw(), 1 << 14);
+/// ```
+///
+/// Bounds that do not fit into the raw integer fail to compile once the type is used:
+///
+/// ```compile_fail
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_fixed! {
+///     struct Overflowing(i16, 8, 0.0..=200.0);
+/// }
+///
+/// let overflowing = Overflowing::new(5.0);
+/// ```
+#[macro_export]
+macro_rules! bound_fixed {
+	($(
+		$(#[$meta: meta])*
+		$vis: vis struct $struct_name: ident($int: ident, $frac: literal, $min: literal ..= $max: literal $(,)?);
+	)*) => {
+		$(
+			$(#[$meta])*
+			#[derive(Debug, Clone, Copy)]
+			$vis struct $struct_name {
+				inner_value: $int,
+			}
+
+			$crate::bound_fixed_impl!($struct_name: $int, $frac, $min, $max);
+		)*
+	};
+}
+
+/// Implements one arithmetic operator between two bounded fixed-point values, along with its assignment form and
+/// the reference-operand variants of both. `$fixed` computes the raw result in `i128`, which is then clamped.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_fixed_operator {
+	(
+		$(#[$attr: meta])*
+		$struct_name: ty;
+		$trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident, $fixed: ident
+	) => {
+		$(#[$attr])*
+		impl std::ops::$trait for $struct_name {
+			type Output = Self;
+
+			fn $method(self, other: Self) -> Self::Output {
+				return Self::clamped(Self::$fixed(self.inner_value as i128, other.inner_value as i128));
+			}
+		}
+
+		impl std::ops::$assign_trait for $struct_name {
+			fn $assign_method(&mut self, other: Self) {
+				*self = std::ops::$trait::$method(*self, other);
+			}
+		}
+
+		$crate::__forward_ref_operator!([] $struct_name, $struct_name; $trait, $method);
+		$crate::__forward_ref_operator!(@assign [] $struct_name, $struct_name; $assign_trait, $assign_method);
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::BTreeSet;
+
+	use crate::prelude::{BoundError, BoundedFixed};
+
+	bound_fixed! {
+		/// Declared through the struct-generating macro.
+		pub struct Health(i32, 16, 0.0..=100.0);
+		struct Speed(i64, 32, -10.0..=10.0);
+		struct Coarse(i16, 4, -100.0..=100.0);
+		struct Unsigned(u32, 8, 1.5..=1000.0);
+		struct Precise(u64, 63, 0.0..=1.99);
+	}
+
+	#[test]
+	fn new_rounds_and_clamps() {
+		assert_eq!(Health::new(150.0), Health::MAX);
+		assert_eq!(Health::new(-1.0), 0.0);
+		assert_eq!(Health::new(f64::NAN), Health::MIN);
+		assert_eq!(Coarse::new(1.03), 1.0);
+		assert_eq!(Coarse::new(1.04), 1.0625);
+		assert_eq!(Unsigned::new(0.0), 1.5);
+		assert_eq!(Unsigned::MIN.raw(), 384);
+		assert_eq!(Speed::new(-3.25).get(), -3.25);
+		assert_eq!(Health::default(), Health::MIN);
+	}
+
+	#[test]
+	fn try_new_reports_violated_bound() {
+		assert_eq!(Health::try_new(50.0), Ok(Health::new(50.0)));
+		assert_eq!(Health::try_new(101.0), Err(BoundError::AboveMax { value: 101.0, max: 100.0 }));
+		assert_eq!(Speed::try_new(-11.0), Err(BoundError::BelowMin { value: -11.0, min: -10.0 }));
+		assert_eq!(Speed::try_new(f64::NAN), Err(BoundError::NaN));
+	}
+
+	#[test]
+	fn raw_round_trips() {
+		let health = Health::from_raw(3 << 15);
+		assert_eq!(health, 1.5);
+		assert_eq!(health.raw(), 3 << 15);
+		assert_eq!(Health::from_raw(i32::MAX), Health::MAX);
+
+		let mut speed = Speed::new(0.0);
+		speed.set_raw(-1);
+		assert_eq!(speed.get(), -1.0 / 4_294_967_296.0);
+		assert_eq!(<Speed as BoundedFixed>::FRAC_BITS, 32);
+		assert_eq!(<Unsigned as BoundedFixed>::MAX_RAW, 256_000);
+	}
+
+	#[test]
+	fn arithmetic_clamps() {
+		let speed = Speed::new(2.5);
+		assert_eq!(speed + Speed::new(1.25), 3.75);
+		assert_eq!(speed - Speed::new(5.0), -2.5);
+		assert_eq!(speed * Speed::new(-2.0), -5.0);
+		assert_eq!(speed * Speed::new(5.0), Speed::MAX);
+		assert_eq!(speed / Speed::new(0.5), 5.0);
+		assert_eq!(speed % Speed::new(1.0), 0.5);
+		assert_eq!(-speed, -2.5);
+		assert_eq!(-Unsigned::new(5.0), Unsigned::MIN);
+
+		assert_eq!(speed / Speed::new(0.0), Speed::MAX);
+		assert_eq!(-speed / Speed::new(0.0), Speed::MIN);
+		assert_eq!(speed % Speed::new(0.0), speed);
+		assert_eq!(-speed % Speed::new(0.0), -2.5);
+		assert_eq!(Health::new(7.5) % Health::new(0.0), 7.5);
+
+		let mut health = Health::new(10.0);
+		health -= Health::new(20.0);
+		assert_eq!(health, 0.0);
+		health += &Health::new(7.5);
+		health *= Health::new(2.0);
+		assert_eq!(health, 15.0);
+	}
+
+	#[test]
+	fn arithmetic_is_exact() {
+		let third = Coarse::new(1.0) / Coarse::new(3.0);
+		assert_eq!(third.raw(), 5);
+		assert_eq!((third * Coarse::new(3.0)).raw(), 15);
+		assert_eq!((Coarse::new(-1.0) * Coarse::new(0.0625)).raw(), -1);
+
+		let tenth = Speed::new(0.1);
+		let sum = (0..10).fold(Speed::new(0.0), |sum, _| sum + tenth);
+		assert_eq!(sum.raw(), tenth.raw() * 10);
+	}
+
+	#[test]
+	fn wide_arithmetic_does_not_overflow() {
+		assert_eq!(Precise::new(1.5) / Precise::new(1.5), 1.0);
+		assert_eq!((Precise::new(1.5) / Precise::new(1.875)).raw(), 7_378_697_629_483_820_646);
+		assert_eq!(Precise::new(1.9) * Precise::new(1.9), Precise::MAX);
+		assert_eq!(Precise::new(1.5) * Precise::new(1.25), 1.875);
+		assert_eq!(Precise::new(1.9) / Precise::new(0.25), Precise::MAX);
+
+		let above_one = Precise::from_raw((1 << 63) + 1);
+		assert!(above_one > 1.0);
+		assert!(above_one != 1.0);
+		assert_eq!(above_one.get(), 1.0);
+	}
+
+	#[test]
+	fn compares_and_converts() {
+		let ordered = [Health::new(3.0), Health::new(1.0), Health::new(2.0)].into_iter().collect::<BTreeSet<_>>();
+		assert_eq!(ordered.into_iter().map(f64::from).collect::<Vec<_>>(), [1.0, 2.0, 3.0]);
+		assert!(Health::new(3.0) > 2.5);
+		assert_eq!(Health::from(2.5f32).to_f32(), 2.5);
+		assert_eq!(f32::from(Health::new(0.5)), 0.5);
+		assert_eq!(Health::new(12.5).to_string(), "12.5");
+		assert_eq!(format!("{:.2}", Health::new(12.5)), "12.50");
+	}
+}
@@ -0,0 +1,115 @@
+/// Generates bounded floats over `MIN..=MAX` with `proptest::arbitrary::any`.
+///
+/// Expands to nothing when the `proptest` feature is disabled.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_float_proptest {
+	($float: ident; [$($generics: tt)*] $struct_name: ty) => {
+		impl<$($generics)*> $crate::proptest::arbitrary::Arbitrary for $struct_name {
+			type Parameters = ();
+			type Strategy = $crate::proptest::strategy::Map<std::ops::RangeInclusive<$float>, fn($float) -> Self>;
+
+			fn arbitrary_with((): ()) -> Self::Strategy {
+				return $crate::proptest::strategy::Strategy::prop_map(Self::MIN..=Self::MAX, Self::new);
+			}
+		}
+	};
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_float_proptest {
+	($($tokens: tt)*) => {};
+}
+
+/// Generates bounded floats over `MIN..=MAX` with `quickcheck::Arbitrary`, shrinking towards `MIN`.
+///
+/// Values are clamped before construction, so that rounding never trips a `Reject` overflow policy.
+///
+/// Expands to nothing when the `quickcheck` feature is disabled.
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_float_quickcheck {
+	($float: ident; [$($generics: tt)*] $struct_name: ty) => {
+		impl<$($generics)*> $crate::quickcheck::Arbitrary for $struct_name {
+			fn arbitrary(g: &mut $crate::quickcheck::Gen) -> Self {
+				let t = <u32 as $crate::quickcheck::Arbitrary>::arbitrary(g) as $float / u32::MAX as $float;
+				let bounds = $crate::prelude::FloatBounds::new(Self::MIN, Self::MAX);
+				return Self::new(bounds.clamp(bounds.denormalize(t)));
+			}
+
+			fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+				let value = self.inner_value;
+				let gap = value - Self::MIN;
+				return Box::new(
+					(0..24)
+						.map(move |halvings| Self::new((value - gap / (1u32 << halvings) as $float).clamp(Self::MIN, Self::MAX)))
+						.take_while(move |candidate| candidate.inner_value < value),
+				);
+			}
+		}
+	};
+}
+
+#[cfg(not(feature = "quickcheck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_float_quickcheck {
+	($($tokens: tt)*) => {};
+}
+
+#[cfg(all(test, feature = "proptest", feature = "quickcheck"))]
+mod tests {
+	use proptest::prelude::*;
+	use quickcheck::{Arbitrary, Gen};
+
+	crate::bound_f64! {
+		struct Volume(0.0..=1.0);
+		struct Angle(0.0..=360.0, overflow = Wrap);
+		struct Strict(0.1..=0.7, overflow = Reject);
+	}
+
+	crate::bound_f32! {
+		struct Gain(-60.0..=12.0);
+	}
+
+	proptest! {
+		#[test]
+		fn proptest_generates_within_bounds(volume: Volume, angle: Angle, gain: Gain) {
+			prop_assert!((0.0..=1.0).contains(&volume.get()));
+			prop_assert!((0.0..360.0).contains(&angle.get()));
+			prop_assert!((-60.0..=12.0).contains(&gain.get()));
+		}
+	}
+
+	#[test]
+	fn quickcheck_generates_within_bounds() {
+		let mut g = Gen::new(10);
+		for _ in 0..1000 {
+			assert!((-60.0..=12.0).contains(&Gain::arbitrary(&mut g).get()));
+			assert!((0.0..360.0).contains(&Angle::arbitrary(&mut g).get()));
+		}
+	}
+
+	#[test]
+	fn quickcheck_respects_reject_policy() {
+		let mut g = Gen::new(10);
+		for _ in 0..1000 {
+			let strict = Strict::arbitrary(&mut g);
+			assert!((0.1..=0.7).contains(&strict.get()));
+			assert!(strict.shrink().all(|candidate| (0.1..=0.7).contains(&candidate.get())));
+		}
+	}
+
+	#[test]
+	fn quickcheck_shrinks_towards_min() {
+		let candidates = Gain::new(4.0).shrink().collect::<Vec<_>>();
+		assert_eq!(candidates.first(), Some(&Gain::new(-60.0)));
+		assert!(candidates.iter().all(|gain| (-60.0..4.0).contains(&gain.get())));
+		assert!(candidates.windows(2).all(|pair| pair[0].get() < pair[1].get()));
+		assert_eq!(Volume::new(0.0).shrink().count(), 0);
+	}
+}
@@ -1,225 +1,144 @@
+/// Implements clamped construction, arithmetic, comparisons and conversions for a struct with a
+/// single private field `inner_value: f32`.
+///
+/// The `impl<...>` form accepts const generic parameters, with `MIN`/`MAX` given as const expressions of them.
+///
+/// `f64` operands are opt-in through [`bound_f32_f64_operands!`], so that unsuffixed literals keep meaning `f32`.
+///
+/// With the `serde` feature, [`bound_float_serde!`] makes the type serialize as a bare number.
+/// With the `rand` feature, it is sampled uniformly over `MIN..=MAX` by `rng.random()`,
+/// and between two values of the type by `rng.random_range(..)`.
+/// With the `proptest` and `quickcheck` features, it implements their `Arbitrary`, generating values in range.
+///
+/// Trailing `option = value` pairs configure the type, see [`BoundedFloat`](crate::prelude::BoundedFloat):
+/// - `overflow`: an [`OverflowPolicy`](crate::prelude::OverflowPolicy), defaults to `Clamp`.
+/// - `nan`: a [`NanPolicy`](crate::prelude::NanPolicy), defaults to `Min`.
+/// - `default`: the value returned by `Default::default`, defaults to `MIN`.
+/// - `deserialize`: the [`OverflowPolicy`](crate::prelude::OverflowPolicy) applied to deserialized values,
+///   defaults to `overflow`. `Reject` fails deserialization instead of panicking.
+/// - `epsilon` and `ulps`: the margin of `is_close`, also returned by `margin()` to pass to `ApproxEq`
+///   and `approx_eq_with`, default to `f32::EPSILON` and `4`.
+/// - `quantum`: the tolerance of `==`, `<` and `Hash`, against both bounded values and primitive floats,
+///   defaults to four units of least precision at the largest magnitude in range.
+///   `epsilon` and `ulps` do not affect `==` with primitive floats, only `is_close`.
+/// - `suffix`: a unit written by `Display` and accepted by `FromStr`, such as `"%"` or `" dB"`, defaults to none.
+/// - `scale`: the factor applied by `Display` and undone by `FromStr`, defaults to `1.0`.
+/// - `decimals`: the most decimals `Display` writes when no precision is given, defaults to all of them.
 #[macro_export]
 macro_rules! bound_f32_impl {
-	($struct_name: ty, $min: literal, $max: literal) => {
-		impl $struct_name {
-			pub const MIN: f32 = $min;
-			pub const MAX: f32 = $max;
-			
-			pub fn new(value: f32) -> Self {
-				return Self { inner_value: f32::clamp(value, Self::MIN, Self::MAX) };
-			}
-			
-			pub fn get(&self) -> f32 {
-				return self.inner_value;
-			}
-			
-			pub fn set(&mut self, value: f32) {
-				self.inner_value = f32::clamp(value, Self::MIN, Self::MAX);
-			}
-		}
-		
-		impl std::ops::Add for $struct_name {
-			type Output = Self;
-		
-			fn add(self, other: Self) -> Self::Output {
-				return Self::new(self.inner_value + other.inner_value);
-			}
-		}
-		
-		impl std::ops::Add<f32> for $struct_name {
-			type Output = f32;
-		
-			fn add(self, other: f32) -> Self::Output {
-				return self.inner_value + other;
-			}
-		}
-		
-		impl std::ops::Add<$struct_name> for f32 {
-			type Output = f32;
-		
-			fn add(self, other: $struct_name) -> Self::Output {
-				return self + other.inner_value;
-			}
-		}
-		
-		impl std::ops::AddAssign for $struct_name {
-			fn add_assign(&mut self, other: Self) {
-				self.set(self.inner_value + other.inner_value);
-			}
-		}
-		
-		impl std::ops::AddAssign<f32> for $struct_name {
-			fn add_assign(&mut self, other: f32) {
-				self.set(self.inner_value + other);
-			}
-		}
-		
-		impl std::ops::Sub for $struct_name {
-			type Output = Self;
-		
-			fn sub(self, other: Self) -> Self::Output {
-				return Self::new(self.inner_value - other.inner_value);
-			}
-		}
-		
-		impl std::ops::Sub<f32> for $struct_name {
-			type Output = f32;
-		
-			fn sub(self, other: f32) -> Self::Output {
-				return self.inner_value - other;
-			}
-		}
-		
-		impl std::ops::Sub<$struct_name> for f32 {
-			type Output = f32;
-		
-			fn sub(self, other: $struct_name) -> Self::Output {
-				return self - other.inner_value;
-			}
-		}
-		
-		impl std::ops::SubAssign for $struct_name {
-			fn sub_assign(&mut self, other: Self) {
-				self.set(self.inner_value - other.inner_value);
-			}
-		}
-		
-		impl std::ops::SubAssign<f32> for $struct_name {
-			fn sub_assign(&mut self, other: f32) {
-				self.set(self.inner_value - other);
-			}
-		}
-		
-		impl std::ops::Div for $struct_name {
-			type Output = Self;
-		
-			fn div(self, other: Self) -> Self::Output {
-				return Self::new(self.inner_value / other.inner_value);
-			}
-		}
-		
-		impl std::ops::Div<f32> for $struct_name {
-			type Output = f32;
-		
-			fn div(self, other: f32) -> Self::Output {
-				return self.inner_value / other;
-			}
-		}
-		
-		impl std::ops::Div<$struct_name> for f32 {
-			type Output = f32;
-		
-			fn div(self, other: $struct_name) -> Self::Output {
-				return self / other.inner_value;
-			}
-		}
-		
-		impl std::ops::DivAssign for $struct_name {
-			fn div_assign(&mut self, other: Self) {
-				self.set(self.inner_value / other.inner_value);
-			}
-		}
-		
-		impl std::ops::DivAssign<f32> for $struct_name {
-			fn div_assign(&mut self, other: f32) {
-				self.set(self.inner_value / other);
-			}
-		}
-		
-		impl std::ops::Mul for $struct_name {
-			type Output = Self;
-		
-			fn mul(self, other: Self) -> Self::Output {
-				return Self::new(self.inner_value * other.inner_value);
-			}
-		}
-		
-		impl std::ops::Mul<f32> for $struct_name {
-			type Output = f32;
-		
-			fn mul(self, other: f32) -> Self::Output {
-				return self.inner_value * other;
-			}
-		}
-		
-		impl std::ops::Mul<$struct_name> for f32 {
-			type Output = f32;
-		
-			fn mul(self, other: $struct_name) -> Self::Output {
-				return self * other.inner_value;
-			}
-		}
-		
-		impl std::ops::MulAssign for $struct_name {
-			fn mul_assign(&mut self, other: Self) {
-				self.set(self.inner_value * other.inner_value);
-			}
-		}
-		
-		impl std::ops::MulAssign<f32> for $struct_name {
-			fn mul_assign(&mut self, other: f32) {
-				self.set(self.inner_value * other);
-			}
-		}
-		
-		impl core::cmp::PartialEq<Self> for $struct_name {
-			fn eq(&self, other: &Self) -> bool {
-				return float_cmp::approx_eq!(f32, self.inner_value, other.inner_value);
-			}
-		}
-		
-		impl core::cmp::PartialEq<f32> for $struct_name {
-			fn eq(&self, other: &f32) -> bool {
-				return float_cmp::approx_eq!(f32, self.inner_value, *other);
-			}
-		}
-		
-		impl core::cmp::PartialEq<$struct_name> for f32 {
-			fn eq(&self, other: &$struct_name) -> bool {
-				return float_cmp::approx_eq!(f32, *self, other.inner_value);
-			}
-		}
-		
-		impl core::cmp::PartialOrd for $struct_name {
-			fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-				return f32::partial_cmp(&self.inner_value, &other.inner_value);
-			}
-		}
-		
-		impl core::cmp::PartialOrd<f32> for $struct_name {
-			fn partial_cmp(&self, other: &f32) -> Option<std::cmp::Ordering> {
-				return f32::partial_cmp(&self.inner_value, &(*other));
-			}
-		}
-		
-		impl core::cmp::PartialOrd<$struct_name> for f32 {
-			fn partial_cmp(&self, other: &$struct_name) -> Option<std::cmp::Ordering> {
-				return f32::partial_cmp(&(*self), &other.inner_value);
-			}
-		}
-		
-		impl core::convert::From<f32> for $struct_name {
-			fn from(value: f32) -> Self {
-				return Self::new(value);
-			}
-		}
-		
-		impl core::default::Default for $struct_name {
-			fn default() -> Self {
-				return Self::new(Self::MIN);
-			}
-		}
-		
-		impl std::ops::Deref for $struct_name {
-			type Target = f32;
-		
-			fn deref(&self) -> &Self::Target {
-				return &self.inner_value;
+	(
+		impl<$(const $param: ident: $param_ty: ty),* $(,)?> $struct_name: ty, $min: expr, $max: expr
+		$(, $option: ident = $value: expr)* $(,)?
+	) => {
+		$crate::__bound_float_impl!(
+			f32; [$(const $param: $param_ty),*] $struct_name, $min, $max; $($option = $value),*
+		);
+	};
+	($struct_name: ty, $min: literal, $max: literal $(, $option: ident = $value: expr)* $(,)?) => {
+		$crate::__bound_float_impl!(f32; [] $struct_name, $min, $max; $($option = $value),*);
+	};
+}
+
+/// Makes a bounded `f32` accept `f64` operands in the assignment operators, comparisons and `From`,
+/// computing in `f64`. Binary operators with `f64` are left out, so that `bound + 1.0` keeps returning `f32`.
+///
+/// Opt-in because it changes what unsuffixed literals mean: with both `PartialOrd<f32>` and `PartialOrd<f64>`,
+/// `bound > 0.3` compares against `0.3f64`, and `Bound::from(0.1)` converts from `0.1f64`.
+///
+/// ```
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_f32! {
+///     pub struct Volume(0.0..=1.0);
+/// }
+///
+/// bound_f32_f64_operands!(Volume);
+///
+/// let mut volume = Volume::from(0.5f64);
+/// volume += 0.25f64;
+/// assert!(volume == 0.75f64);
+/// ```
+#[macro_export]
+macro_rules! bound_f32_f64_operands {
+	(impl<$(const $param: ident: $param_ty: ty),* $(,)?> $struct_name: ty) => {
+		$crate::__bound_float_mixed_impl!(f32, f64 => f64; [$(const $param: $param_ty),*] $struct_name);
+	};
+	($struct_name: ty) => {
+		$crate::__bound_float_mixed_impl!(f32, f64 => f64; [] $struct_name);
+	};
+}
+
+/// Declares one or more bounded `f32` newtypes and implements [`bound_f32_impl!`] for each of them.
+///
+/// Attributes (including doc comments) and visibility are forwarded to the generated struct,
+/// which always derives `Debug`, `Clone` and `Copy`, and implements [`bound_float_serde!`].
+/// Options are given after the range, as in [`bound_f32_impl!`].
+///
+/// ```
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_f32! {
+///     /// Master volume, from muted to full.
+///     pub struct Volume(0.0..=1.0);
+///     pub struct Angle(0.0..=360.0, overflow = Wrap);
+/// }
+///
+/// assert_eq!(Volume::new(1.5).get(), Volume::MAX);
+/// assert_eq!(Angle::new(370.0).get(), 10.0);
+/// ```
+#[macro_export]
+macro_rules! bound_f32 {
+	($(
+		$(#[$meta: meta])*
+		$vis: vis struct $struct_name: ident($min: literal ..= $max: literal $(, $option: ident = $value: expr)* $(,)?);
+	)*) => {
+		$(
+			$(#[$meta])*
+			#[derive(Debug, Clone, Copy)]
+			$vis struct $struct_name {
+				inner_value: f32,
 			}
-		}
+
+			$crate::bound_f32_impl!($struct_name, $min, $max $(, $option = $value)*);
+			$crate::bound_float_serde!($struct_name);
+		)*
 	};
 }
 
+/// A bounded `f32` whose range, `MIN_NUM / DENOM ..= MAX_NUM / DENOM`, is given by const generics.
+///
+/// Saves declaring a newtype per range: `BoundedF32<0, 1>` is a ratio, `BoundedF32<0, 100>` a percentage
+/// and `BoundedF32<-1, 1, 2>` spans `-0.5..=0.5`.
+///
+/// A zero `DENOM` or `MIN_NUM > MAX_NUM` fails to compile once the type is used:
+///
+/// ```compile_fail
+/// use houtamelo_utils::prelude::*;
+///
+/// let inverted = BoundedF32::<1, 0>::new(0.5);
+/// ```
+///
+/// ```compile_fail
+/// use houtamelo_utils::prelude::*;
+///
+/// let undefined = BoundedF32::<0, 1, 0>::new(0.5);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedF32<const MIN_NUM: i64, const MAX_NUM: i64, const DENOM: u64 = 1> {
+	inner_value: f32,
+}
+
+bound_f32_impl!(
+	impl<const MIN_NUM: i64, const MAX_NUM: i64, const DENOM: u64> BoundedF32<MIN_NUM, MAX_NUM, DENOM>,
+	{
+		assert!(DENOM != 0, "`BoundedF32` requires a non-zero `DENOM`");
+		MIN_NUM as f32 / DENOM as f32
+	},
+	MAX_NUM as f32 / DENOM as f32
+);
+
+crate::bound_float_serde!(impl<const MIN_NUM: i64, const MAX_NUM: i64, const DENOM: u64> BoundedF32<MIN_NUM, MAX_NUM, DENOM>);
+
 #[cfg(all(test, feature = "serde"))]
 mod tests {
 	use serde::{Deserialize, Serialize};
@@ -230,7 +149,31 @@ mod tests {
 		inner_value: f32
 	}
 
+	use super::BoundedF32;
+	use crate::prelude::BoundedF64;
+
 	bound_f32_impl!(B_f32, -5.0, 5.0);
+	bound_f32_f64_operands!(B_f32);
+
+	bound_f32! {
+		/// Declared through the struct-generating macro.
+		pub struct Declared_f32(-1.0..=1.0);
+		struct Private_f32(0.0..=10.0);
+		struct Angle_f32(0.0..=360.0, overflow = Wrap);
+		struct Strict_f32(0.0..=1.0, overflow = OverflowPolicy::Reject,);
+		struct NanMin_f32(-1.0..=1.0, nan = Min);
+		struct NanDefault_f32(-1.0..=1.0, nan = Default, default = 0.5);
+		struct NanReject_f32(-1.0..=1.0, nan = NanPolicy::Reject);
+		struct NanDebugPanic_f32(-1.0..=1.0, nan = DebugPanic);
+		struct StrictDe_f32(0.0..=1.0, deserialize = Reject);
+		struct Coarse_f32(0.0..=10.0, quantum = 0.5);
+		struct Loose_f32(0.0..=10.0, epsilon = 0.01, ulps = 0);
+		struct Gain_f32(-60.0..=12.0, suffix = " dB", decimals = 1);
+		struct Far_f32(990.0..=1010.0);
+		struct Wide_f32(-5.0..=5.0);
+	}
+
+	use crate::prelude::{ApproxEq, BoundError, BoundedFloat, F32Margin, NanPolicy, OverflowPolicy, ParseBoundedError};
 
 	#[test]
 	fn test_new_bound_f32_within_range() {
@@ -387,4 +330,410 @@ mod tests {
 		let value2 = B_f32::new(2.0);
 		assert!(float_cmp::approx_eq!(f32, value1.inner_value, value2.inner_value));
 	}
+
+	#[test]
+	fn test_declared_bound_f32_clamps() {
+		assert!(float_cmp::approx_eq!(f32, Declared_f32::new(3.0).get(), Declared_f32::MAX));
+		assert!(float_cmp::approx_eq!(f32, Declared_f32::new(-3.0).get(), Declared_f32::MIN));
+		assert!(float_cmp::approx_eq!(f32, Private_f32::default().get(), 0.0));
+	}
+
+	#[test]
+	fn test_declared_bound_f32_operators() {
+		let a = Declared_f32::new(0.75);
+		let b = a;
+		assert_eq!(a + b, Declared_f32::MAX);
+		assert_eq!(a - b, 0.0);
+		assert!(a > Declared_f32::new(0.5));
+	}
+
+	#[test]
+	fn test_generic_bound_f32_range() {
+		assert!(float_cmp::approx_eq!(f32, BoundedF32::<0, 100>::MAX, 100.0));
+		assert!(float_cmp::approx_eq!(f32, BoundedF32::<-1, 1, 2>::MIN, -0.5));
+		assert!(float_cmp::approx_eq!(f32, BoundedF32::<-1, 1, 2>::new(3.0).get(), 0.5));
+		assert!(float_cmp::approx_eq!(f32, BoundedF32::<2, 4>::default().get(), 2.0));
+	}
+
+	#[test]
+	fn test_generic_bound_f32_operators() {
+		let ratio = BoundedF32::<0, 1>::new(0.75);
+		assert_eq!(ratio + ratio, 1.0);
+		assert_eq!(ratio * 2.0, 1.5);
+		assert_eq!(*ratio, 0.75);
+		assert_eq!(BoundedF32::<0, 1>::from(-1.0), 0.0);
+	}
+
+	#[test]
+	fn test_wrap_overflow_policy() {
+		let mut angle = Angle_f32::new(370.0);
+		assert!(float_cmp::approx_eq!(f32, angle.get(), 10.0));
+		angle -= 20.0;
+		assert!(float_cmp::approx_eq!(f32, angle.get(), 350.0));
+	}
+
+	#[test]
+	#[should_panic(expected = "above the maximum")]
+	fn test_reject_overflow_policy() {
+		let mut strict = Strict_f32::new(0.5);
+		strict += 1.0;
+	}
+
+	#[test]
+	fn test_try_new_and_try_set() {
+		assert_eq!(B_f32::try_new(6.0).unwrap_err(), BoundError::AboveMax { value: 6.0, max: 5.0 });
+		assert_eq!(Angle_f32::try_new(-1.0).unwrap_err(), BoundError::BelowMin { value: -1.0, min: 0.0 });
+
+		let mut bound = B_f32::new(1.0);
+		assert!(bound.try_set(-7.0).is_err());
+		assert_eq!(bound, 1.0);
+		assert!(bound.try_set(-4.0).is_ok());
+		assert_eq!(bound, -4.0);
+	}
+
+	#[test]
+	fn test_set_reporting_excess() {
+		let mut angle = Angle_f32::default();
+		assert!(float_cmp::approx_eq!(f32, angle.set_reporting_excess(365.0), 5.0));
+		assert!(float_cmp::approx_eq!(f32, angle.get(), Angle_f32::MAX));
+		assert!(float_cmp::approx_eq!(f32, angle.set_reporting_excess(-3.0), -3.0));
+		assert!(float_cmp::approx_eq!(f32, angle.set_reporting_excess(3.0), 0.0));
+	}
+
+	#[test]
+	fn test_nan_policy_min() {
+		assert_eq!(NanMin_f32::new(f32::NAN), NanMin_f32::MIN);
+
+		let mut bound = NanMin_f32::new(0.5);
+		bound.set(f32::NAN);
+		assert_eq!(bound, NanMin_f32::MIN);
+
+		let mut bound = NanMin_f32::new(0.0);
+		bound /= 0.0;
+		assert_eq!(bound, NanMin_f32::MIN);
+		assert_eq!(NanMin_f32::new(0.0) / NanMin_f32::new(0.0), NanMin_f32::MIN);
+	}
+
+	#[test]
+	fn test_nan_policy_default() {
+		assert_eq!(NanDefault_f32::default(), 0.5);
+		assert_eq!(NanDefault_f32::new(f32::NAN), 0.5);
+
+		let mut bound = NanDefault_f32::new(-1.0);
+		bound *= f32::NAN;
+		assert_eq!(bound, 0.5);
+	}
+
+	#[test]
+	#[should_panic(expected = "NaN")]
+	fn test_nan_policy_reject() {
+		let mut bound = NanReject_f32::new(0.5);
+		bound -= f32::NAN;
+	}
+
+	#[test]
+	#[cfg_attr(debug_assertions, should_panic(expected = "NaN"))]
+	fn test_nan_policy_debug_panic() {
+		let mut bound = NanDebugPanic_f32::new(0.5);
+		bound += f32::NAN;
+		assert_eq!(bound, NanDebugPanic_f32::MIN);
+	}
+
+	#[test]
+	fn test_infinity_is_clamped() {
+		assert_eq!(B_f32::new(f32::INFINITY), B_f32::MAX);
+		assert_eq!(B_f32::new(f32::NEG_INFINITY), B_f32::MIN);
+		assert_eq!(Angle_f32::new(f32::INFINITY), Angle_f32::MAX);
+		assert_eq!(NanMin_f32::try_new(f32::NAN).unwrap_err(), BoundError::NaN);
+	}
+
+	#[test]
+	fn test_serde_bare_number() {
+		assert_eq!(ron::to_string(&Wide_f32::new(2.5)).unwrap(), "2.5");
+		assert_eq!(ron::from_str::<Wide_f32>("2.5").unwrap(), 2.5);
+		assert_eq!(ron::to_string(&BoundedF32::<0, 1>::new(0.5)).unwrap(), "0.5");
+	}
+
+	#[test]
+	fn test_serde_clamps_out_of_range() {
+		assert_eq!(ron::from_str::<Wide_f32>("10.0").unwrap(), Wide_f32::MAX);
+		assert_eq!(ron::from_str::<Angle_f32>("370.0").unwrap(), 10.0);
+		assert_eq!(ron::from_str::<NanDefault_f32>("NaN").unwrap(), 0.5);
+	}
+
+	#[test]
+	fn test_serde_rejects_out_of_range() {
+		let err = ron::from_str::<StrictDe_f32>("1.5").unwrap_err();
+		assert!(err.to_string().contains("above the maximum"));
+		assert!(ron::from_str::<Strict_f32>("-0.5").is_err());
+		assert!(ron::from_str::<NanReject_f32>("NaN").is_err());
+		assert_eq!(ron::from_str::<StrictDe_f32>("0.25").unwrap(), 0.25);
+	}
+
+	#[test]
+	fn test_serde_keeps_own_derive() {
+		// `bound_f32_impl!` leaves serde to the struct, which derives it here.
+		assert_eq!(ron::to_string(&B_f32::new(2.5)).unwrap(), "(inner_value:2.5)");
+	}
+
+	#[test]
+	fn test_serde_in_struct() {
+		#[derive(serde::Serialize, serde::Deserialize)]
+		struct Config {
+			volume: Declared_f32,
+		}
+
+		let config: Config = ron::from_str("(volume: 3.0)").unwrap();
+		assert_eq!(config.volume, Declared_f32::MAX);
+		assert_eq!(ron::to_string(&config).unwrap(), "(volume:1.0)");
+	}
+
+	#[test]
+	fn test_total_ordering() {
+		let mut values = vec![B_f32::new(3.0), B_f32::new(-2.0), B_f32::new(4.5), B_f32::new(0.0)];
+		values.sort();
+		assert_eq!(values, [-2.0, 0.0, 3.0, 4.5].map(B_f32::new));
+
+		let map = std::collections::BTreeMap::from([(B_f32::new(1.0), "one"), (B_f32::new(-1.0), "minus one")]);
+		assert_eq!(map.first_key_value(), Some((&B_f32::new(-1.0), &"minus one")));
+		assert_eq!(B_f32::new(1.0).max(B_f32::new(2.0)), 2.0);
+	}
+
+	#[test]
+	fn test_eq_consistent_with_hash() {
+		use std::collections::HashSet;
+
+		let sum = B_f32::new(0.1) + B_f32::new(0.2);
+		assert_eq!(sum, B_f32::new(0.3));
+
+		let set = HashSet::from([B_f32::new(0.3), B_f32::new(1.0)]);
+		assert!(set.contains(&sum));
+		assert!(!set.contains(&B_f32::new(0.31)));
+
+		let mut small_set = crate::prelude::SmallSet::<B_f32, 4>::new();
+		small_set.insert(B_f32::new(0.3));
+		small_set.insert(sum);
+		assert_eq!(small_set.len(), 1);
+	}
+
+	#[test]
+	fn test_quantum_option() {
+		assert_eq!(Coarse_f32::new(1.1), Coarse_f32::new(0.9));
+		assert_ne!(Coarse_f32::new(1.1), Coarse_f32::new(1.4));
+		assert!(Coarse_f32::new(1.1) < Coarse_f32::new(1.4));
+		assert_eq!(Coarse_f32::new(1.1).quantised(), 2);
+	}
+
+	#[test]
+	fn test_primitive_comparisons_agree() {
+		let almost_half = B_f32::new(0.5 - 1e-7);
+		assert!(almost_half == 0.5f32);
+		assert!(!(almost_half < 0.5f32));
+		assert_eq!(almost_half, B_f32::new(0.5));
+		assert!(Coarse_f32::new(1.1) == 0.9f32);
+		assert!(Coarse_f32::new(1.1) <= 0.8f32);
+		assert!(!Coarse_f32::new(1.1).eq(&f32::NAN));
+		assert_eq!(Coarse_f32::new(1.1).partial_cmp(&f32::NAN), None);
+
+		// The default quantum follows the magnitude of the range, not `f32::EPSILON`.
+		let far = Far_f32::new(1000.0);
+		assert!(far == 1000.0f32 + 1e-4);
+		assert!(far != 1000.1f32);
+
+		// The margin options only apply to `is_close`.
+		assert!(Loose_f32::new(1.0) != 1.005f32);
+		assert!(Loose_f32::new(1.0).is_close(1.005));
+	}
+
+	#[test]
+	fn test_margin_options() {
+		assert!(Loose_f32::new(1.0).is_close(1.005));
+		assert!(!Loose_f32::new(1.0).is_close(1.02));
+		assert!(!B_f32::new(1.0).is_close(1.005));
+		assert!(Loose_f32::new(1.0).approx_eq(Loose_f32::new(1.005), Loose_f32::margin()));
+		assert_eq!(Loose_f32::margin().epsilon, 0.01);
+		assert_eq!(Loose_f32::margin().ulps, 0);
+		assert_eq!(Coarse_f32::EPSILON, f32::EPSILON);
+	}
+
+	#[test]
+	fn test_approx_eq_with() {
+		let bound = B_f32::new(1.0);
+		assert!(bound.approx_eq_with(1.05, (0.1, 0)));
+		assert!(!bound.approx_eq_with(1.05, F32Margin::default()));
+		assert!(bound.approx_eq(B_f32::new(1.05), (0.1, 0)));
+		assert!(float_cmp::approx_eq!(B_f32, bound, B_f32::new(1.0 + f32::EPSILON)));
+	}
+
+	#[test]
+	fn test_f64_operands() {
+		let mut bound = B_f32::from(2.0f64);
+		assert_eq!(bound, 2.0f64);
+		assert!(bound < 2.5f64);
+
+		bound += 0.5f64;
+		assert_eq!(bound, 2.5);
+		bound *= 10.0f64;
+		assert_eq!(bound, B_f32::MAX);
+		bound -= 1.0f64;
+		bound /= 2.0f64;
+		assert_eq!(bound.get(), 2.0);
+		assert_eq!(bound + 1.0, 3.0f32);
+	}
+
+	#[test]
+	fn test_literals_stay_f32() {
+		let bound = Declared_f32::from(0.1);
+		assert_eq!(bound.get(), 0.1f32);
+		assert!(bound > 0.05);
+		assert!(!(bound > 0.1));
+		assert_eq!(bound, 0.1);
+	}
+
+	#[test]
+	fn test_from_bounded() {
+		let declared = Declared_f32::from_bounded(B_f32::new(-3.0));
+		assert_eq!(declared, Declared_f32::MIN);
+		assert_eq!(Angle_f32::from_bounded(BoundedF32::<0, 400>::new(370.0)), 10.0);
+		assert_eq!(
+			Strict_f32::try_from_bounded(B_f32::new(2.0)).unwrap_err(),
+			BoundError::AboveMax { value: 2.0, max: 1.0 }
+		);
+		assert_eq!(Strict_f32::try_from_bounded(B_f32::new(0.5)).unwrap(), 0.5);
+	}
+
+	#[test]
+	fn test_remapping() {
+		let stat = BoundedF32::<0, 100>::new(25.0);
+		assert_eq!(stat.normalized(), 0.25);
+		assert_eq!(Declared_f32::new(0.0).normalized(), 0.5);
+		assert_eq!(Declared_f32::from_normalized(0.75), 0.5);
+		assert_eq!(Declared_f32::from_normalized(2.0), Declared_f32::MAX);
+
+		let ratio: BoundedF64<0, 1> = stat.remap_to();
+		assert_eq!(ratio, 0.25f64);
+		assert_eq!(stat.remap_to::<Angle_f32>(), 90.0);
+		assert_eq!(stat.remap_to::<Declared_f32>(), -0.5);
+	}
+
+	#[test]
+	fn test_interpolation() {
+		let from = Private_f32::new(2.0);
+		let to = Private_f32::new(6.0);
+		assert_eq!(from.lerp(to, 0.25), 3.0);
+		assert_eq!(from.lerp(to, 4.0), Private_f32::MAX);
+		assert_eq!(Private_f32::new(5.0).inverse_lerp(from, to), 0.75);
+		assert_eq!(from.inverse_lerp(from, from), 0.0);
+
+		assert_eq!(from.smoothstep(to, 0.5), 4.0);
+		assert_eq!(from.smoothstep(to, 0.25), 2.625);
+		assert_eq!(from.smoothstep(to, -1.0), 2.0);
+		assert_eq!(from.smoothstep(to, 2.0), 6.0);
+	}
+
+	#[test]
+	fn test_neg_and_rem() {
+		assert_eq!(-Declared_f32::new(0.5), -0.5);
+		assert_eq!(-Private_f32::new(3.0), Private_f32::MIN);
+		assert_eq!(-&Declared_f32::new(-1.0), 1.0);
+
+		let mut bound = Private_f32::new(7.5);
+		assert_eq!(bound % Private_f32::new(2.0), 1.5);
+		assert_eq!(bound % 2.0, 1.5);
+		assert_eq!(8.0 % bound, 0.5);
+		bound %= 4.0;
+		assert_eq!(bound, 3.5);
+		bound %= Private_f32::new(2.0);
+		assert_eq!(bound, 1.5);
+	}
+
+	#[test]
+	fn test_sum_and_product() {
+		let values = vec![Private_f32::new(2.0), Private_f32::new(3.0), Private_f32::new(4.0)];
+		assert_eq!(values.iter().sum::<Private_f32>(), 9.0);
+		assert_eq!(values.iter().product::<Private_f32>(), Private_f32::MAX);
+		assert_eq!(values[..2].iter().copied().product::<Private_f32>(), 6.0);
+		assert_eq!(values.into_iter().rev().sum::<Private_f32>(), 9.0);
+
+		assert_eq!(std::iter::empty::<Private_f32>().sum::<Private_f32>(), 0.0);
+		assert_eq!(std::iter::empty::<Declared_f32>().product::<Declared_f32>(), 1.0);
+
+		// Clamps after each addition, like `+`
+		let sum: Declared_f32 = [1.0, 1.0, -1.0].map(Declared_f32::new).into_iter().sum();
+		assert_eq!(sum, 0.0);
+	}
+
+	#[test]
+	#[allow(clippy::op_ref)]
+	fn test_reference_operands() {
+		let a = Private_f32::new(6.0);
+		let b = Private_f32::new(3.0);
+		assert_eq!(&a + &b, 9.0);
+		assert_eq!(&a - b, 3.0);
+		assert_eq!(a * &b, Private_f32::MAX);
+		assert_eq!(&a / &2.0, 3.0);
+		assert_eq!(&12.0 / &a, 2.0);
+		assert_eq!(&a % &b, 0.0);
+
+		let mut c = a;
+		c += &b;
+		c -= &1.0;
+		assert_eq!(c, 8.0);
+	}
+
+	#[test]
+	fn test_const_contexts() {
+		const CLAMPED: Private_f32 = Private_f32::new(20.0);
+		const WRAPPED: Angle_f32 = Angle_f32::new(-90.0);
+		const CHECKED: Declared_f32 = crate::bounded!(Declared_f32, -0.5);
+		const GENERIC: BoundedF32<0, 100> = crate::bounded!(BoundedF32<0, 100>, 42.0);
+		const VALUE: f32 = CLAMPED.get();
+		const NORMALIZED: f32 = WRAPPED.normalized();
+		const FAILED: Result<Strict_f32, BoundError> = Strict_f32::try_new(2.0);
+		const NAN: NanDefault_f32 = NanDefault_f32::new(f32::NAN);
+		const SET: Private_f32 = {
+			let mut bound = Private_f32::new(1.0);
+			bound.set(-1.0);
+			bound
+		};
+
+		assert_eq!(VALUE, 10.0);
+		assert_eq!(WRAPPED, 270.0);
+		assert_eq!(NORMALIZED, 0.75);
+		assert_eq!(CHECKED, -0.5);
+		assert_eq!(GENERIC, 42.0);
+		assert_eq!(FAILED.unwrap_err(), BoundError::AboveMax { value: 2.0, max: 1.0 });
+		assert_eq!(NAN, 0.5);
+		assert_eq!(SET, 0.0);
+	}
+
+	#[test]
+	#[should_panic(expected = "below the minimum")]
+	fn test_new_in_range_panics_at_runtime() {
+		let value = std::hint::black_box(-2.0);
+		Declared_f32::new_in_range(value);
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(Private_f32::new(2.5).to_string(), "2.5");
+		assert_eq!(format!("{:.2}", Private_f32::new(2.5)), "2.50");
+		assert_eq!(format!("{:>6.1}", Private_f32::new(2.5)), "   2.5");
+		assert_eq!(Gain_f32::new(-3.24).to_string(), "-3.2 dB");
+		assert_eq!(format!("{:.3}", Gain_f32::new(-3.24)), "-3.240 dB");
+	}
+
+	#[test]
+	fn test_from_str() {
+		assert_eq!("2.5".parse::<Private_f32>(), Ok(Private_f32::new(2.5)));
+		assert_eq!("-3.5 dB".parse::<Gain_f32>(), Ok(Gain_f32::new(-3.5)));
+		assert_eq!("-3.5dB".parse::<Gain_f32>(), Ok(Gain_f32::new(-3.5)));
+		assert_eq!(
+			"20 dB".parse::<Gain_f32>(),
+			Err(ParseBoundedError::OutOfBounds(BoundError::AboveMax { value: 20.0, max: 12.0 }))
+		);
+		assert_eq!("NaN".parse::<Private_f32>(), Err(ParseBoundedError::OutOfBounds(BoundError::NaN)));
+		assert!(matches!("2.5 %".parse::<Gain_f32>(), Err(ParseBoundedError::Invalid(_))));
+		assert!("400".parse::<Angle_f32>().is_err());
+	}
 }
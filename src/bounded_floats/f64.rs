@@ -1,262 +1,98 @@
+/// Implements clamped construction, arithmetic, comparisons and conversions for a struct with a
+/// single private field `inner_value: f64`.
+///
+/// `f32` operands are accepted as well, arithmetic with them returns `f64`.
+///
+/// The `impl<...>` form accepts const generic parameters, with `MIN`/`MAX` given as const expressions of them.
+///
+/// Accepts the same trailing `option = value` pairs as [`bound_f32_impl!`].
 #[macro_export]
 macro_rules! bound_f64_impl {
-    ($struct_name:ty, $min:literal, $max:literal) => {
-        impl $struct_name {
-            pub const MIN: f64 = $min;
-            pub const MAX: f64 = $max;
-
-            pub fn new(value: f64) -> Self {
-                return Self {
-                    inner_value: f64::clamp(value, Self::MIN, Self::MAX),
-                };
-            }
-
-            pub fn get(&self) -> f64 { return self.inner_value; }
-
-            pub fn set(&mut self, value: f64) { self.inner_value = f64::clamp(value, Self::MIN, Self::MAX); }
-        }
-
-        impl std::ops::Add for $struct_name {
-            type Output = Self;
-
-            fn add(self, other: Self) -> Self::Output { return Self::new(self.inner_value + other.inner_value); }
-        }
-
-        impl std::ops::Add<f64> for $struct_name {
-            type Output = f64;
-
-            fn add(self, other: f64) -> Self::Output { return self.inner_value + other; }
-        }
-
-        impl std::ops::Add<$struct_name> for f64 {
-            type Output = f64;
-
-            fn add(self, other: $struct_name) -> Self::Output { return self + other.inner_value; }
-        }
-
-        impl std::ops::Add<f32> for $struct_name {
-            type Output = f64;
-
-            fn add(self, other: f32) -> Self::Output { return self.inner_value + other as f64; }
-        }
-
-        impl std::ops::Add<$struct_name> for f32 {
-            type Output = f64;
-
-            fn add(self, other: $struct_name) -> Self::Output { return self as f64 + other.inner_value; }
-        }
-
-        impl std::ops::AddAssign for $struct_name {
-            fn add_assign(&mut self, other: Self) { self.set(self.inner_value + other.inner_value); }
-        }
-
-        impl std::ops::AddAssign<f64> for $struct_name {
-            fn add_assign(&mut self, other: f64) { self.set(self.inner_value + other); }
-        }
-
-        impl std::ops::AddAssign<f32> for $struct_name {
-            fn add_assign(&mut self, other: f32) { self.set(self.inner_value + other as f64); }
-        }
-
-        impl std::ops::Sub for $struct_name {
-            type Output = Self;
-
-            fn sub(self, other: Self) -> Self::Output { return Self::new(self.inner_value - other.inner_value); }
-        }
-
-        impl std::ops::Sub<f64> for $struct_name {
-            type Output = f64;
-
-            fn sub(self, other: f64) -> Self::Output { return self.inner_value - other; }
-        }
-
-        impl std::ops::Sub<$struct_name> for f64 {
-            type Output = f64;
-
-            fn sub(self, other: $struct_name) -> Self::Output { return self - other.inner_value; }
-        }
-
-        impl std::ops::Sub<f32> for $struct_name {
-            type Output = f64;
-
-            fn sub(self, other: f32) -> Self::Output { return self.inner_value - other as f64; }
-        }
-
-        impl std::ops::Sub<$struct_name> for f32 {
-            type Output = f64;
-
-            fn sub(self, other: $struct_name) -> Self::Output { return self as f64 - other.inner_value; }
-        }
-
-        impl std::ops::SubAssign for $struct_name {
-            fn sub_assign(&mut self, other: Self) { self.set(self.inner_value - other.inner_value); }
-        }
-
-        impl std::ops::SubAssign<f64> for $struct_name {
-            fn sub_assign(&mut self, other: f64) { self.set(self.inner_value - other); }
-        }
-
-        impl std::ops::SubAssign<f32> for $struct_name {
-            fn sub_assign(&mut self, other: f32) { self.set(self.inner_value - other as f64); }
-        }
-
-        impl std::ops::Div for $struct_name {
-            type Output = Self;
-
-            fn div(self, other: Self) -> Self::Output { return Self::new(self.inner_value / other.inner_value); }
-        }
-
-        impl std::ops::Div<f64> for $struct_name {
-            type Output = f64;
-
-            fn div(self, other: f64) -> Self::Output { return self.inner_value / other; }
-        }
-
-        impl std::ops::Div<$struct_name> for f64 {
-            type Output = f64;
-
-            fn div(self, other: $struct_name) -> Self::Output { return self / other.inner_value; }
-        }
-
-        impl std::ops::Div<f32> for $struct_name {
-            type Output = f64;
-
-            fn div(self, other: f32) -> Self::Output { return self.inner_value / other as f64; }
-        }
-
-        impl std::ops::Div<$struct_name> for f32 {
-            type Output = f64;
-
-            fn div(self, other: $struct_name) -> Self::Output { return self as f64 / other.inner_value; }
-        }
-
-        impl std::ops::DivAssign for $struct_name {
-            fn div_assign(&mut self, other: Self) { self.set(self.inner_value / other.inner_value); }
-        }
-
-        impl std::ops::DivAssign<f64> for $struct_name {
-            fn div_assign(&mut self, other: f64) { self.set(self.inner_value / other); }
-        }
-
-        impl std::ops::DivAssign<f32> for $struct_name {
-            fn div_assign(&mut self, other: f32) { self.set(self.inner_value / other as f64); }
-        }
-
-        impl std::ops::Mul for $struct_name {
-            type Output = Self;
-
-            fn mul(self, other: Self) -> Self::Output { return Self::new(self.inner_value * other.inner_value); }
-        }
-
-        impl std::ops::Mul<f64> for $struct_name {
-            type Output = f64;
-
-            fn mul(self, other: f64) -> Self::Output { return self.inner_value * other; }
-        }
-
-        impl std::ops::Mul<$struct_name> for f64 {
-            type Output = f64;
-
-            fn mul(self, other: $struct_name) -> Self::Output { return self * other.inner_value; }
-        }
-
-        impl std::ops::Mul<f32> for $struct_name {
-            type Output = f64;
-
-            fn mul(self, other: f32) -> Self::Output { return self.inner_value * other as f64; }
-        }
-
-        impl std::ops::Mul<$struct_name> for f32 {
-            type Output = f64;
-
-            fn mul(self, other: $struct_name) -> Self::Output { return self as f64 * other.inner_value; }
-        }
-
-        impl std::ops::MulAssign for $struct_name {
-            fn mul_assign(&mut self, other: Self) { self.set(self.inner_value * other.inner_value); }
-        }
-
-        impl std::ops::MulAssign<f64> for $struct_name {
-            fn mul_assign(&mut self, other: f64) { self.set(self.inner_value * other); }
-        }
-
-        impl std::ops::MulAssign<f32> for $struct_name {
-            fn mul_assign(&mut self, other: f32) { self.set(self.inner_value * other as f64); }
-        }
-
-        impl core::cmp::PartialEq<Self> for $struct_name {
-            fn eq(&self, other: &Self) -> bool {
-                return float_cmp::approx_eq!(f64, self.inner_value, other.inner_value);
-            }
-        }
-
-        impl core::cmp::PartialEq<f64> for $struct_name {
-            fn eq(&self, other: &f64) -> bool { return float_cmp::approx_eq!(f64, self.inner_value, *other); }
-        }
-
-        impl core::cmp::PartialEq<$struct_name> for f64 {
-            fn eq(&self, other: &$struct_name) -> bool { return float_cmp::approx_eq!(f64, *self, other.inner_value); }
-        }
-
-        impl core::cmp::PartialEq<f32> for $struct_name {
-            fn eq(&self, other: &f32) -> bool { return float_cmp::approx_eq!(f64, self.inner_value, *other as f64); }
-        }
-
-        impl core::cmp::PartialEq<$struct_name> for f32 {
-            fn eq(&self, other: &$struct_name) -> bool {
-                return float_cmp::approx_eq!(f64, *self as f64, other.inner_value);
-            }
-        }
-
-        impl core::cmp::PartialOrd for $struct_name {
-            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-                return f64::partial_cmp(&self.inner_value, &other.inner_value);
-            }
-        }
-
-        impl core::cmp::PartialOrd<f64> for $struct_name {
-            fn partial_cmp(&self, other: &f64) -> Option<std::cmp::Ordering> {
-                return f64::partial_cmp(&self.inner_value, other);
-            }
-        }
-
-        impl core::cmp::PartialOrd<$struct_name> for f64 {
-            fn partial_cmp(&self, other: &$struct_name) -> Option<std::cmp::Ordering> {
-                return f64::partial_cmp(self, &other.inner_value);
-            }
-        }
-
-        impl core::cmp::PartialOrd<f32> for $struct_name {
-            fn partial_cmp(&self, other: &f32) -> Option<std::cmp::Ordering> {
-                return f64::partial_cmp(&self.inner_value, &(*other as f64));
-            }
-        }
+    (
+        impl<$(const $param:ident: $param_ty:ty),* $(,)?> $struct_name:ty, $min:expr, $max:expr
+        $(, $option:ident = $value:expr)* $(,)?
+    ) => {
+        $crate::__bound_float_impl!(
+            f64; [$(const $param: $param_ty),*] $struct_name, $min, $max; $($option = $value),*
+        );
+        $crate::__bound_float_mixed_impl!(f64, f32 => f64; [$(const $param: $param_ty),*] $struct_name);
+        $crate::__bound_float_mixed_impl!(@operators f64, f32 => f64; [$(const $param: $param_ty),*] $struct_name);
+    };
+    ($struct_name:ty, $min:literal, $max:literal $(, $option:ident = $value:expr)* $(,)?) => {
+        $crate::__bound_float_impl!(f64; [] $struct_name, $min, $max; $($option = $value),*);
+        $crate::__bound_float_mixed_impl!(f64, f32 => f64; [] $struct_name);
+        $crate::__bound_float_mixed_impl!(@operators f64, f32 => f64; [] $struct_name);
+    };
+}
 
-        impl core::cmp::PartialOrd<$struct_name> for f32 {
-            fn partial_cmp(&self, other: &$struct_name) -> Option<std::cmp::Ordering> {
-                return f64::partial_cmp(&(*self as f64), &other.inner_value);
+/// Declares one or more bounded `f64` newtypes and implements [`bound_f64_impl!`] for each of them.
+///
+/// Attributes (including doc comments) and visibility are forwarded to the generated struct,
+/// which always derives `Debug`, `Clone` and `Copy`, and implements [`bound_float_serde!`].
+/// Options are given after the range, as in [`bound_f32_impl!`].
+///
+/// ```
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_f64! {
+///     /// Fraction of the level that has been explored.
+///     pub struct Exploration(0.0..=1.0);
+/// }
+///
+/// assert_eq!(Exploration::new(-0.5).get(), Exploration::MIN);
+/// ```
+#[macro_export]
+macro_rules! bound_f64 {
+    ($(
+        $(#[$meta:meta])*
+        $vis:vis struct $struct_name:ident($min:literal ..= $max:literal $(, $option:ident = $value:expr)* $(,)?);
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy)]
+            $vis struct $struct_name {
+                inner_value: f64,
             }
-        }
-
-        impl core::convert::From<f64> for $struct_name {
-            fn from(value: f64) -> Self { return Self::new(value); }
-        }
 
-        impl core::convert::From<f32> for $struct_name {
-            fn from(value: f32) -> Self { return Self::new(value as f64); }
-        }
+            $crate::bound_f64_impl!($struct_name, $min, $max $(, $option = $value)*);
+            $crate::bound_float_serde!($struct_name);
+        )*
+    };
+}
 
-        impl core::default::Default for $struct_name {
-            fn default() -> Self { return Self::new(Self::MIN); }
-        }
+/// A bounded `f64` whose range, `MIN_NUM / DENOM ..= MAX_NUM / DENOM`, is given by const generics.
+///
+/// Saves declaring a newtype per range: `BoundedF64<0, 1>` is a ratio, `BoundedF64<0, 100>` a percentage
+/// and `BoundedF64<-1, 1, 2>` spans `-0.5..=0.5`.
+///
+/// A zero `DENOM` or `MIN_NUM > MAX_NUM` fails to compile once the type is used:
+///
+/// ```compile_fail
+/// use houtamelo_utils::prelude::*;
+///
+/// let inverted = BoundedF64::<1, 0>::new(0.5);
+/// ```
+///
+/// ```compile_fail
+/// use houtamelo_utils::prelude::*;
+///
+/// let undefined = BoundedF64::<0, 1, 0>::new(0.5);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BoundedF64<const MIN_NUM: i64, const MAX_NUM: i64, const DENOM: u64 = 1> {
+    inner_value: f64,
+}
 
-        impl std::ops::Deref for $struct_name {
-            type Target = f64;
+bound_f64_impl!(
+    impl<const MIN_NUM: i64, const MAX_NUM: i64, const DENOM: u64> BoundedF64<MIN_NUM, MAX_NUM, DENOM>,
+    {
+        assert!(DENOM != 0, "`BoundedF64` requires a non-zero `DENOM`");
+        MIN_NUM as f64 / DENOM as f64
+    },
+    MAX_NUM as f64 / DENOM as f64
+);
 
-            fn deref(&self) -> &Self::Target { return &self.inner_value; }
-        }
-    };
-}
+crate::bound_float_serde!(impl<const MIN_NUM: i64, const MAX_NUM: i64, const DENOM: u64> BoundedF64<MIN_NUM, MAX_NUM, DENOM>);
 
 #[cfg(test)]
 mod tests {
@@ -266,7 +102,28 @@ mod tests {
         inner_value: f64,
     }
 
+    use super::BoundedF64;
+    use crate::prelude::BoundedF32;
+
     bound_f64_impl!(B_f64, -5.0, 5.0);
+    crate::bound_float_serde!(B_f64);
+
+    bound_f64! {
+        /// Declared through the struct-generating macro.
+        pub struct Declared_f64(-1.0..=1.0);
+        struct Private_f64(0.0..=10.0);
+        struct Angle_f64(0.0..=360.0, overflow = Wrap);
+        struct Strict_f64(0.0..=1.0, overflow = OverflowPolicy::Reject,);
+        struct NanMin_f64(-1.0..=1.0, nan = Min);
+        struct NanDefault_f64(-1.0..=1.0, nan = NanPolicy::Default, default = 0.5);
+        struct NanReject_f64(-1.0..=1.0, nan = Reject);
+        struct NanDebugPanic_f64(-1.0..=1.0, nan = DebugPanic);
+        struct StrictDe_f64(0.0..=1.0, deserialize = Reject);
+        struct Loose_f64(0.0..=10.0, epsilon = 0.01, ulps = 0);
+        struct Volume_f64(0.0..=1.0, suffix = "%", scale = 100.0);
+    }
+
+    use crate::prelude::{BoundError, NanPolicy, OverflowPolicy, ParseBoundedError};
 
     #[test]
     fn test_new_bound_f64_within_range() {
@@ -423,4 +280,287 @@ mod tests {
         let value2 = B_f64::new(2.0);
         assert!(float_cmp::approx_eq!(f64, value1.inner_value, value2.inner_value));
     }
+
+    #[test]
+    fn test_declared_bound_f64_clamps() {
+        assert!(float_cmp::approx_eq!(f64, Declared_f64::new(3.0).get(), Declared_f64::MAX));
+        assert!(float_cmp::approx_eq!(f64, Declared_f64::new(-3.0).get(), Declared_f64::MIN));
+        assert!(float_cmp::approx_eq!(f64, Private_f64::default().get(), 0.0));
+    }
+
+    #[test]
+    fn test_declared_bound_f64_operators() {
+        let a = Declared_f64::new(0.75);
+        let b = a;
+        assert_eq!(a + b, Declared_f64::MAX);
+        assert_eq!(a - b, 0.0);
+        assert!(a > Declared_f64::new(0.5));
+    }
+
+    #[test]
+    fn test_generic_bound_f64_range() {
+        assert!(float_cmp::approx_eq!(f64, BoundedF64::<0, 100>::MAX, 100.0));
+        assert!(float_cmp::approx_eq!(f64, BoundedF64::<-1, 1, 2>::MIN, -0.5));
+        assert!(float_cmp::approx_eq!(f64, BoundedF64::<-1, 1, 2>::new(3.0).get(), 0.5));
+        assert!(float_cmp::approx_eq!(f64, BoundedF64::<2, 4>::default().get(), 2.0));
+    }
+
+    #[test]
+    fn test_generic_bound_f64_operators() {
+        let ratio = BoundedF64::<0, 1>::new(0.75);
+        assert_eq!(ratio + ratio, 1.0);
+        assert_eq!(ratio * 2.0f32, 1.5);
+        assert_eq!(*ratio, 0.75);
+        assert_eq!(BoundedF64::<0, 1>::from(-1.0f32), 0.0);
+    }
+
+    #[test]
+    fn test_wrap_overflow_policy() {
+        let mut angle = Angle_f64::new(370.0);
+        assert!(float_cmp::approx_eq!(f64, angle.get(), 10.0));
+        angle -= 20.0f32;
+        assert!(float_cmp::approx_eq!(f64, angle.get(), 350.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "below the minimum")]
+    fn test_reject_overflow_policy() {
+        let mut strict = Strict_f64::new(0.5);
+        strict -= 1.0;
+    }
+
+    #[test]
+    fn test_try_new_and_try_set() {
+        assert_eq!(B_f64::try_new(6.0).unwrap_err(), BoundError::AboveMax { value: 6.0, max: 5.0 });
+        assert_eq!(Angle_f64::try_new(-1.0).unwrap_err(), BoundError::BelowMin { value: -1.0, min: 0.0 });
+
+        let mut bound = B_f64::new(1.0);
+        assert!(bound.try_set(-7.0).is_err());
+        assert_eq!(bound, 1.0);
+        assert!(bound.try_set(-4.0).is_ok());
+        assert_eq!(bound, -4.0);
+    }
+
+    #[test]
+    fn test_set_reporting_excess() {
+        let mut angle = Angle_f64::default();
+        assert!(float_cmp::approx_eq!(f64, angle.set_reporting_excess(365.0), 5.0));
+        assert!(float_cmp::approx_eq!(f64, angle.get(), Angle_f64::MAX));
+        assert!(float_cmp::approx_eq!(f64, angle.set_reporting_excess(-3.0), -3.0));
+        assert!(float_cmp::approx_eq!(f64, angle.set_reporting_excess(3.0), 0.0));
+    }
+
+    #[test]
+    fn test_nan_policy_min() {
+        assert_eq!(NanMin_f64::new(f64::NAN), NanMin_f64::MIN);
+
+        let mut bound = NanMin_f64::new(0.5);
+        bound.set(f64::NAN);
+        assert_eq!(bound, NanMin_f64::MIN);
+
+        let mut bound = NanMin_f64::new(0.0);
+        bound /= 0.0f32;
+        assert_eq!(bound, NanMin_f64::MIN);
+        assert_eq!(NanMin_f64::new(0.0) / NanMin_f64::new(0.0), NanMin_f64::MIN);
+    }
+
+    #[test]
+    fn test_nan_policy_default() {
+        assert_eq!(NanDefault_f64::default(), 0.5);
+        assert_eq!(NanDefault_f64::new(f64::NAN), 0.5);
+
+        let mut bound = NanDefault_f64::new(-1.0);
+        bound *= f64::NAN;
+        assert_eq!(bound, 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn test_nan_policy_reject() {
+        let mut bound = NanReject_f64::new(0.5);
+        bound -= f32::NAN;
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "NaN"))]
+    fn test_nan_policy_debug_panic() {
+        let mut bound = NanDebugPanic_f64::new(0.5);
+        bound += f64::NAN;
+        assert_eq!(bound, NanDebugPanic_f64::MIN);
+    }
+
+    #[test]
+    fn test_infinity_is_clamped() {
+        assert_eq!(B_f64::new(f64::INFINITY), B_f64::MAX);
+        assert_eq!(B_f64::new(f64::NEG_INFINITY), B_f64::MIN);
+        assert_eq!(Angle_f64::new(f64::INFINITY), Angle_f64::MAX);
+        assert_eq!(NanMin_f64::try_new(f64::NAN).unwrap_err(), BoundError::NaN);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        assert_eq!(ron::to_string(&B_f64::new(-2.5)).unwrap(), "-2.5");
+        assert_eq!(ron::from_str::<B_f64>("-10.0").unwrap(), B_f64::MIN);
+        assert_eq!(ron::from_str::<BoundedF64<0, 100>>("50.0").unwrap(), 50.0);
+        assert!(ron::from_str::<StrictDe_f64>("2.0").is_err());
+        assert!(ron::from_str::<StrictDe_f64>("NaN").is_err());
+    }
+
+    #[test]
+    fn test_total_ordering() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let sorted = BTreeSet::from([B_f64::new(1.5), B_f64::new(-3.0), B_f64::new(20.0)]);
+        assert_eq!(sorted.into_iter().collect::<Vec<_>>(), [-3.0, 1.5, 5.0].map(B_f64::new));
+
+        let set = HashSet::from([B_f64::new(0.1) + B_f64::new(0.2), B_f64::new(0.3)]);
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_margin_options() {
+        use crate::prelude::{ApproxEq, BoundedFloat};
+
+        assert!(Loose_f64::new(1.0).is_close(1.005));
+        assert!(!Loose_f64::new(1.0).is_close(1.02));
+        assert!(!B_f64::new(1.0).is_close(1.005));
+        assert!(Loose_f64::new(1.0).approx_eq(Loose_f64::new(1.005), Loose_f64::margin()));
+        assert!(B_f64::new(1.0).approx_eq_with(1.05, (0.1, 0)));
+        assert!(B_f64::new(1.0).approx_eq(B_f64::new(1.05), (0.1, 0)));
+    }
+
+    #[test]
+    fn test_remapping() {
+        let stat = BoundedF64::<0, 100>::new(25.0);
+        assert_eq!(stat.normalized(), 0.25);
+        assert_eq!(Declared_f64::new(0.0).normalized(), 0.5);
+        assert_eq!(Declared_f64::from_normalized(0.75), 0.5);
+        assert_eq!(Declared_f64::from_normalized(2.0), Declared_f64::MAX);
+
+        let ratio: BoundedF32<0, 1> = stat.remap_to();
+        assert_eq!(ratio, 0.25f32);
+        assert_eq!(stat.remap_to::<Angle_f64>(), 90.0);
+        assert_eq!(stat.remap_to::<Declared_f64>(), -0.5);
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let from = Private_f64::new(2.0);
+        let to = Private_f64::new(6.0);
+        assert_eq!(from.lerp(to, 0.25), 3.0);
+        assert_eq!(from.lerp(to, 4.0), Private_f64::MAX);
+        assert_eq!(Private_f64::new(5.0).inverse_lerp(from, to), 0.75);
+        assert_eq!(from.inverse_lerp(from, from), 0.0);
+
+        assert_eq!(from.smoothstep(to, 0.5), 4.0);
+        assert_eq!(from.smoothstep(to, 0.25), 2.625);
+        assert_eq!(from.smoothstep(to, -1.0), 2.0);
+        assert_eq!(from.smoothstep(to, 2.0), 6.0);
+    }
+
+    #[test]
+    fn test_neg_and_rem() {
+        assert_eq!(-Declared_f64::new(0.5), -0.5);
+        assert_eq!(-Private_f64::new(3.0), Private_f64::MIN);
+        assert_eq!(-&Declared_f64::new(-1.0), 1.0);
+
+        let mut bound = Private_f64::new(7.5);
+        assert_eq!(bound % Private_f64::new(2.0), 1.5);
+        assert_eq!(bound % 2.0, 1.5);
+        assert_eq!(8.0 % bound, 0.5);
+        bound %= 4.0;
+        assert_eq!(bound, 3.5);
+        bound %= Private_f64::new(2.0);
+        assert_eq!(bound, 1.5);
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        let values = vec![Private_f64::new(2.0), Private_f64::new(3.0), Private_f64::new(4.0)];
+        assert_eq!(values.iter().sum::<Private_f64>(), 9.0);
+        assert_eq!(values.iter().product::<Private_f64>(), Private_f64::MAX);
+        assert_eq!(values[..2].iter().copied().product::<Private_f64>(), 6.0);
+        assert_eq!(values.into_iter().rev().sum::<Private_f64>(), 9.0);
+
+        assert_eq!(std::iter::empty::<Private_f64>().sum::<Private_f64>(), 0.0);
+        assert_eq!(std::iter::empty::<Declared_f64>().product::<Declared_f64>(), 1.0);
+
+        // Clamps after each addition, like `+`
+        let sum: Declared_f64 = [1.0, 1.0, -1.0].map(Declared_f64::new).into_iter().sum();
+        assert_eq!(sum, 0.0);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_reference_operands() {
+        let a = Private_f64::new(6.0);
+        let b = Private_f64::new(3.0);
+        assert_eq!(&a + &b, 9.0);
+        assert_eq!(&a - b, 3.0);
+        assert_eq!(a * &b, Private_f64::MAX);
+        assert_eq!(&a / &2.0, 3.0);
+        assert_eq!(&12.0 / &a, 2.0);
+        assert_eq!(&a % &b, 0.0);
+
+        let mut c = a;
+        c += &b;
+        c -= &1.0;
+        c /= &2.0f32;
+        assert_eq!(c, 4.0);
+        assert_eq!(&c * &2.0f32, 8.0);
+    }
+
+    #[test]
+    fn test_const_contexts() {
+        const CLAMPED: Private_f64 = Private_f64::new(20.0);
+        const WRAPPED: Angle_f64 = Angle_f64::new(-90.0);
+        const CHECKED: Declared_f64 = crate::bounded!(Declared_f64, -0.5);
+        const GENERIC: BoundedF64<0, 100> = crate::bounded!(BoundedF64<0, 100>, 42.0);
+        const VALUE: f64 = CLAMPED.get();
+        const NORMALIZED: f64 = WRAPPED.normalized();
+        const FAILED: Result<Strict_f64, BoundError> = Strict_f64::try_new(2.0);
+        const NAN: NanDefault_f64 = NanDefault_f64::new(f64::NAN);
+        const SET: Private_f64 = {
+            let mut bound = Private_f64::new(1.0);
+            bound.set(-1.0);
+            bound
+        };
+
+        assert_eq!(VALUE, 10.0);
+        assert_eq!(WRAPPED, 270.0);
+        assert_eq!(NORMALIZED, 0.75);
+        assert_eq!(CHECKED, -0.5);
+        assert_eq!(GENERIC, 42.0);
+        assert_eq!(FAILED.unwrap_err(), BoundError::AboveMax { value: 2.0, max: 1.0 });
+        assert_eq!(NAN, 0.5);
+        assert_eq!(SET, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "below the minimum")]
+    fn test_new_in_range_panics_at_runtime() {
+        let value = std::hint::black_box(-2.0);
+        Declared_f64::new_in_range(value);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Private_f64::new(2.5).to_string(), "2.5");
+        assert_eq!(format!("{:.2}", Private_f64::new(2.5)), "2.50");
+        assert_eq!(format!("{:<5}|", Private_f64::new(2.5)), "2.5  |");
+        assert_eq!(Volume_f64::new(0.75).to_string(), "75%");
+        assert_eq!(format!("{:.1}", Volume_f64::new(0.75)), "75.0%");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(" 2.5 ".parse::<Private_f64>(), Ok(Private_f64::new(2.5)));
+        assert_eq!("75%".parse::<Volume_f64>(), Ok(Volume_f64::new(0.75)));
+        assert_eq!(
+            "-1".parse::<Private_f64>(),
+            Err(ParseBoundedError::OutOfBounds(BoundError::BelowMin { value: -1.0, min: 0.0 }))
+        );
+        assert!(matches!("loud".parse::<Volume_f64>(), Err(ParseBoundedError::Invalid(_))));
+    }
 }
@@ -0,0 +1,100 @@
+use std::{
+	fmt::{self, Display},
+	num::ParseFloatError,
+	str::FromStr,
+};
+
+use super::{BoundError, PrimitiveFloat};
+
+/// Why parsing a bounded float failed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseBoundedError {
+	/// The text is not a number.
+	Invalid(ParseFloatError),
+	/// The number is NaN or out of the type's range.
+	OutOfBounds(BoundError),
+}
+
+impl Display for ParseBoundedError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ParseBoundedError::Invalid(err) => write!(f, "invalid bounded float: {err}"),
+			ParseBoundedError::OutOfBounds(err) => write!(f, "{err}"),
+		}
+	}
+}
+
+impl std::error::Error for ParseBoundedError {}
+
+impl From<ParseFloatError> for ParseBoundedError {
+	fn from(err: ParseFloatError) -> Self { ParseBoundedError::Invalid(err) }
+}
+
+/// Writes `value` followed by `suffix`, with the formatter's precision or, if it has none, at most `decimals` decimals.
+/// The formatter's width and alignment apply to the whole text, numbers align right by default.
+#[doc(hidden)]
+pub fn fmt_bounded<F: PrimitiveFloat + Display>(
+	value: F,
+	suffix: &str,
+	decimals: Option<usize>,
+	f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+	let text = match (f.precision(), decimals) {
+		(Some(precision), _) => format!("{value:.precision$}{suffix}"),
+		(None, Some(decimals)) => {
+			let factor = 10f64.powi(decimals as i32);
+			let rounded = F::from_f64((value.to_f64() * factor).round() / factor);
+			format!("{rounded}{suffix}")
+		}
+		(None, None) => format!("{value}{suffix}"),
+	};
+
+	let width = f.width().unwrap_or(0);
+	match f.align() {
+		Some(fmt::Alignment::Left) => write!(f, "{text:<width$}"),
+		Some(fmt::Alignment::Center) => write!(f, "{text:^width$}"),
+		Some(fmt::Alignment::Right) | None => write!(f, "{text:>width$}"),
+	}
+}
+
+/// Parses `text` as a float, ignoring surrounding whitespace and an optional `suffix`.
+#[doc(hidden)]
+pub fn parse_bounded<F: FromStr<Err = ParseFloatError>>(text: &str, suffix: &str) -> Result<F, ParseFloatError> {
+	let text = text.trim();
+	let suffix = suffix.trim();
+	let number = match text.strip_suffix(suffix) {
+		Some(number) if !suffix.is_empty() => number.trim_end(),
+		_ => text,
+	};
+
+	number.parse()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	struct Shown(f32, &'static str, Option<usize>);
+
+	impl Display for Shown {
+		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_bounded(self.0, self.1, self.2, f) }
+	}
+
+	#[test]
+	fn formats_with_suffix_and_decimals() {
+		assert_eq!(Shown(0.1, "", None).to_string(), "0.1");
+		assert_eq!(Shown(-3.0, " dB", None).to_string(), "-3 dB");
+		assert_eq!(Shown(1.0 / 3.0, "%", Some(2)).to_string(), "0.33%");
+		assert_eq!(format!("{:.3}", Shown(1.0 / 3.0, "%", Some(2))), "0.333%");
+		assert_eq!(format!("{:^7}|", Shown(2.5, "x", None)), " 2.5x  |");
+	}
+
+	#[test]
+	fn parses_with_optional_suffix() {
+		assert_eq!(parse_bounded::<f32>(" 12.5 dB ", " dB"), Ok(12.5));
+		assert_eq!(parse_bounded::<f32>("12.5", " dB"), Ok(12.5));
+		assert_eq!(parse_bounded::<f64>("50%", "%"), Ok(50.0));
+		assert!(parse_bounded::<f64>("50 %%", "%").is_err());
+		assert!(parse_bounded::<f64>("loud", "").is_err());
+	}
+}
@@ -0,0 +1,185 @@
+/// Implements conversions and arithmetic between pairs of distinct bounded types, which may wrap different floats.
+///
+/// For each `A, B;` pair:
+/// - `From<A> for B` and `From<B> for A`, through [`BoundedFloat::from_bounded`](crate::prelude::BoundedFloat::from_bounded),
+///   so values are brought into the target's range by its policies.
+///   Prefix the pair with `try` to implement `TryFrom` instead, failing with a
+///   [`BoundError`](crate::prelude::BoundError) when the value is out of the target's range.
+/// - `+`, `-`, `*`, `/` and `%` in both directions, by value or reference, returning a raw `f64`.
+///
+/// ```
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_f32! {
+///     pub struct Ratio(0.0..=1.0);
+/// }
+///
+/// bound_f64! {
+///     pub struct Gain(0.0..=2.0);
+///     pub struct Percent(0.0..=100.0);
+/// }
+///
+/// bound_float_interop! {
+///     Ratio, Gain;
+///     try Ratio, Percent;
+/// }
+///
+/// assert_eq!(Ratio::from(Gain::new(1.5)), Ratio::MAX);
+/// assert!(Ratio::try_from(Percent::new(50.0)).is_err());
+/// assert_eq!(Ratio::new(0.5) * Gain::new(2.0), 1.0);
+/// ```
+#[macro_export]
+macro_rules! bound_float_interop {
+	() => {};
+	(try $a: ty, $b: ty; $($rest: tt)*) => {
+		$crate::bound_float_interop!(@try_from $a => $b);
+		$crate::bound_float_interop!(@try_from $b => $a);
+		$crate::bound_float_interop!(@operators $a, $b);
+		$crate::bound_float_interop!($($rest)*);
+	};
+	($a: ty, $b: ty; $($rest: tt)*) => {
+		$crate::bound_float_interop!(@from $a => $b);
+		$crate::bound_float_interop!(@from $b => $a);
+		$crate::bound_float_interop!(@operators $a, $b);
+		$crate::bound_float_interop!($($rest)*);
+	};
+	(@from $source: ty => $target: ty) => {
+		impl core::convert::From<$source> for $target {
+			fn from(value: $source) -> Self {
+				return <Self as $crate::prelude::BoundedFloat>::from_bounded(value);
+			}
+		}
+	};
+	(@try_from $source: ty => $target: ty) => {
+		impl core::convert::TryFrom<$source> for $target {
+			type Error = $crate::prelude::BoundError;
+
+			fn try_from(value: $source) -> Result<Self, Self::Error> {
+				return <Self as $crate::prelude::BoundedFloat>::try_from_bounded(value);
+			}
+		}
+	};
+	(@operators $a: ty, $b: ty) => {
+		$crate::bound_float_interop!(@operator $a, $b; Add, add, +);
+		$crate::bound_float_interop!(@operator $b, $a; Add, add, +);
+		$crate::bound_float_interop!(@operator $a, $b; Sub, sub, -);
+		$crate::bound_float_interop!(@operator $b, $a; Sub, sub, -);
+		$crate::bound_float_interop!(@operator $a, $b; Mul, mul, *);
+		$crate::bound_float_interop!(@operator $b, $a; Mul, mul, *);
+		$crate::bound_float_interop!(@operator $a, $b; Div, div, /);
+		$crate::bound_float_interop!(@operator $b, $a; Div, div, /);
+		$crate::bound_float_interop!(@operator $a, $b; Rem, rem, %);
+		$crate::bound_float_interop!(@operator $b, $a; Rem, rem, %);
+	};
+	(@operator $lhs: ty, $rhs: ty; $trait: ident, $method: ident, $op: tt) => {
+		impl std::ops::$trait<$rhs> for $lhs {
+			type Output = f64;
+
+			fn $method(self, other: $rhs) -> Self::Output {
+				use $crate::prelude::{BoundedFloat, PrimitiveFloat};
+				return <$lhs as BoundedFloat>::get(&self).to_f64() $op <$rhs as BoundedFloat>::get(&other).to_f64();
+			}
+		}
+
+		$crate::__forward_ref_operator!([] $lhs, $rhs; $trait, $method);
+	};
+}
+
+/// Declares an `f32` and an `f64` bounded newtype sharing a range, options and attributes,
+/// with [`bound_float_interop!`] between them.
+///
+/// ```
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_float_pair! {
+///     /// Master volume, from muted to full.
+///     pub struct Volume / Volume64(0.0..=1.0);
+/// }
+///
+/// let precise = Volume64::new(0.25);
+/// assert_eq!(Volume::from(precise), 0.25);
+/// assert_eq!(Volume64::from(Volume::new(2.0)), 1.0);
+/// ```
+#[macro_export]
+macro_rules! bound_float_pair {
+	($(
+		$(#[$meta: meta])*
+		$vis: vis struct $f32_name: ident / $f64_name: ident(
+			$min: literal ..= $max: literal $(, $option: ident = $value: expr)* $(,)?
+		);
+	)*) => {
+		$(
+			$crate::bound_f32! {
+				$(#[$meta])*
+				$vis struct $f32_name($min..=$max $(, $option = $value)*);
+			}
+
+			$crate::bound_f64! {
+				$(#[$meta])*
+				$vis struct $f64_name($min..=$max $(, $option = $value)*);
+			}
+
+			$crate::bound_float_interop!($f32_name, $f64_name;);
+		)*
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::prelude::*;
+
+	bound_float_pair! {
+		struct Volume / Volume64(0.0..=1.0);
+		struct Angle / Angle64(0.0..=360.0, overflow = Wrap);
+	}
+
+	bound_f32! {
+		struct Ratio(0.0..=1.0);
+	}
+
+	bound_f64! {
+		struct Gain(-2.0..=2.0);
+		struct Percent(0.0..=100.0);
+	}
+
+	bound_float_interop! {
+		Ratio, Gain;
+		try Ratio, Percent;
+		Gain, Percent;
+	}
+
+	#[test]
+	fn pair_converts_both_ways() {
+		assert_eq!(Volume64::from(Volume::new(0.25)), 0.25);
+		assert_eq!(Volume::from(Volume64::new(0.75)), 0.75);
+		assert_eq!(Angle::from(Angle64::new(370.0)), 10.0);
+		assert_eq!(Angle64::MAX, 360.0);
+	}
+
+	#[test]
+	fn from_brings_value_into_target_range() {
+		assert_eq!(Ratio::from(Gain::new(-1.0)), Ratio::MIN);
+		assert_eq!(Gain::from(Ratio::new(0.5)), 0.5);
+		assert_eq!(Gain::from(Percent::new(50.0)), Gain::MAX);
+		assert_eq!(Percent::from(Gain::new(-1.0)), Percent::MIN);
+	}
+
+	#[test]
+	fn try_from_fails_out_of_range() {
+		assert_eq!(Ratio::try_from(Percent::new(0.5)).unwrap(), 0.5);
+		assert_eq!(
+			Ratio::try_from(Percent::new(50.0)).unwrap_err(),
+			BoundError::AboveMax { value: 50.0, max: 1.0 }
+		);
+		assert_eq!(Percent::try_from(Ratio::new(1.0)).unwrap(), 1.0);
+	}
+
+	#[test]
+	fn cross_type_arithmetic_returns_f64() {
+		let sum: f64 = Ratio::new(0.5) + Gain::new(1.5);
+		assert!(float_cmp::approx_eq!(f64, sum, 2.0));
+		assert!(float_cmp::approx_eq!(f64, Gain::new(1.0) - Ratio::new(0.25), 0.75));
+		assert!(float_cmp::approx_eq!(f64, Volume::new(0.5) * Volume64::new(0.5), 0.25));
+		assert!(float_cmp::approx_eq!(f64, Percent::new(50.0) / Gain::new(2.0), 25.0));
+	}
+}
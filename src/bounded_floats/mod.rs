@@ -1,2 +1,588 @@
+use float_cmp::{ApproxEq, F32Margin, F64Margin};
+
+pub use self::{f32::*, f64::*, format::*, policy::*, ranged::*, resource::*, units::*};
+
+mod arbitrary_impl;
 mod f64;
 mod f32;
+mod format;
+mod interop;
+mod operators;
+mod policy;
+mod rand_impl;
+mod ranged;
+mod resource;
+mod serde_impl;
+mod units;
+
+/// Implemented by every type generated through [`bound_f32_impl!`] and [`bound_f64_impl!`].
+///
+/// The defaulted constants are the generator's options, the macros override them with `option = value` pairs:
+/// `bound_f32_impl!(Angle, 0.0, 360.0, overflow = Wrap)`.
+pub trait BoundedFloat {
+	type Float: PrimitiveFloat;
+
+	const MIN: Self::Float;
+	const MAX: Self::Float;
+
+	/// Option `overflow`, applied by `new`, `set` and the operators.
+	const OVERFLOW: OverflowPolicy = OverflowPolicy::Clamp;
+	/// Option `nan`, applied by `new`, `set` and the operators before `OVERFLOW`.
+	const NAN: NanPolicy = NanPolicy::Min;
+	/// Option `default`, the value of [`Default::default`].
+	const DEFAULT: Self::Float = Self::MIN;
+	/// Option `deserialize`, applied to deserialized values instead of `OVERFLOW`,
+	/// `Reject` makes deserialization fail rather than panic.
+	const DESERIALIZE: OverflowPolicy = Self::OVERFLOW;
+	/// Option `epsilon`, the absolute tolerance of `is_close`, part of [`Self::margin`].
+	const EPSILON: Self::Float = <Self::Float as PrimitiveFloat>::EPSILON;
+	/// Option `ulps`, the tolerance in units of least precision of `is_close`, part of [`Self::margin`].
+	const ULPS: <Self::Float as PrimitiveFloat>::Ulps = <Self::Float as PrimitiveFloat>::ULPS;
+	/// Option `quantum`, the tolerance of every `==` and `<`, against both bounded values and primitive floats,
+	/// and of `Hash`.
+	///
+	/// Values are rounded to the nearest multiple of the quantum above `MIN` before being compared or hashed,
+	/// so values closer than it are usually, but not always, equal: rounding may still split them.
+	/// `epsilon` and `ulps` play no part in `==` and `<` against primitive floats, which keeps them consistent with
+	/// `Eq`, `Ord` and `Hash`, compare with `is_close` to apply the margin.
+	/// Defaults to [`FloatBounds::default_quantum`], a few units of least precision at the largest magnitude in range.
+	const QUANTUM: Option<Self::Float> = None;
+	/// Option `suffix`, written after the value by `Display` and accepted after it by `FromStr`, e.g. `"%"` or `" dB"`.
+	const SUFFIX: &'static str = "";
+	/// Option `scale`, the factor `Display` applies to the value and `FromStr` undoes,
+	/// e.g. `100.0` to show a ratio as a percentage.
+	const SCALE: Self::Float = <Self::Float as PrimitiveFloat>::ONE;
+	/// Option `decimals`, the most decimals `Display` writes when the format has no precision.
+	const DECIMALS: Option<usize> = None;
+
+	fn new(value: Self::Float) -> Self;
+	fn try_new(value: Self::Float) -> Result<Self, BoundError>
+	where Self: Sized;
+	fn get(&self) -> Self::Float;
+
+	/// Converts from another bounded type through `new`, so `other` is brought into this type's range.
+	fn from_bounded<B: BoundedFloat>(other: B) -> Self
+	where Self: Sized {
+		Self::new(Self::Float::from_f64(other.get().to_f64()))
+	}
+
+	/// Converts from another bounded type through `try_new`, failing if `other` is out of this type's range.
+	fn try_from_bounded<B: BoundedFloat>(other: B) -> Result<Self, BoundError>
+	where Self: Sized {
+		Self::try_new(Self::Float::from_f64(other.get().to_f64()))
+	}
+
+	/// The margin made of `EPSILON` and `ULPS`, used by `is_close`.
+	///
+	/// [`ApproxEq`] and `approx_eq_with` take their margin as an argument, pass this one to use the type's tolerance.
+	fn margin() -> <Self::Float as ApproxEq>::Margin {
+		<Self::Float as PrimitiveFloat>::margin(Self::EPSILON, Self::ULPS)
+	}
+}
+
+/// Builds a bounded value in a const context, failing to compile if `value` is NaN or out of the type's range.
+///
+/// ```
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_f32! {
+///     pub struct Volume(0.0..=1.0);
+/// }
+///
+/// const DEFAULT_VOLUME: Volume = bounded!(Volume, 0.8);
+/// assert_eq!(DEFAULT_VOLUME.get(), 0.8);
+/// ```
+///
+/// ```compile_fail
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_f32! {
+///     pub struct Volume(0.0..=1.0);
+/// }
+///
+/// let too_loud = bounded!(Volume, 1.5);
+/// ```
+#[macro_export]
+macro_rules! bounded {
+	($struct_name: ty, $value: expr $(,)?) => {
+		const { <$struct_name>::new_in_range($value) }
+	};
+}
+
+/// The primitive floats a [`BoundedFloat`] can wrap.
+pub trait PrimitiveFloat: Copy + ApproxEq {
+	/// The integer type counting units of least precision in [`ApproxEq::Margin`].
+	type Ulps: Copy;
+
+	/// The default epsilon of [`ApproxEq::Margin`].
+	const EPSILON: Self;
+	/// The default ULPs of [`ApproxEq::Margin`].
+	const ULPS: Self::Ulps;
+	/// `1.0`.
+	const ONE: Self;
+
+	fn margin(epsilon: Self, ulps: Self::Ulps) -> Self::Margin;
+	fn to_f64(self) -> f64;
+	fn from_f64(value: f64) -> Self;
+}
+
+impl PrimitiveFloat for f32 {
+	type Ulps = i32;
+
+	const EPSILON: Self = f32::EPSILON;
+	const ULPS: i32 = 4;
+	const ONE: Self = 1.0;
+
+	fn margin(epsilon: f32, ulps: i32) -> F32Margin { F32Margin { epsilon, ulps } }
+	fn to_f64(self) -> f64 { self as f64 }
+	fn from_f64(value: f64) -> Self { value as f32 }
+}
+
+impl PrimitiveFloat for f64 {
+	type Ulps = i64;
+
+	const EPSILON: Self = f64::EPSILON;
+	const ULPS: i64 = 4;
+	const ONE: Self = 1.0;
+
+	fn margin(epsilon: f64, ulps: i64) -> F64Margin { F64Margin { epsilon, ulps } }
+	fn to_f64(self) -> f64 { self }
+	fn from_f64(value: f64) -> Self { value }
+}
+
+/// Translates a generator option into the matching [`BoundedFloat`] constant.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_float_option {
+	($float: ident; overflow = $value: expr) => {
+		const OVERFLOW: $crate::prelude::OverflowPolicy = {
+			#[allow(unused_imports)]
+			use $crate::prelude::OverflowPolicy::*;
+			$value
+		};
+	};
+	($float: ident; nan = $value: expr) => {
+		const NAN: $crate::prelude::NanPolicy = {
+			#[allow(unused_imports)]
+			use $crate::prelude::NanPolicy::*;
+			$value
+		};
+	};
+	($float: ident; default = $value: expr) => {
+		const DEFAULT: $float = $value;
+	};
+	($float: ident; deserialize = $value: expr) => {
+		const DESERIALIZE: $crate::prelude::OverflowPolicy = {
+			#[allow(unused_imports)]
+			use $crate::prelude::OverflowPolicy::*;
+			$value
+		};
+	};
+	($float: ident; epsilon = $value: expr) => {
+		const EPSILON: $float = $value;
+	};
+	($float: ident; ulps = $value: expr) => {
+		const ULPS: <$float as $crate::prelude::PrimitiveFloat>::Ulps = $value;
+	};
+	($float: ident; quantum = $value: expr) => {
+		const QUANTUM: Option<$float> = Some($value);
+	};
+	($float: ident; suffix = $value: expr) => {
+		const SUFFIX: &'static str = $value;
+	};
+	($float: ident; scale = $value: expr) => {
+		const SCALE: $float = $value;
+	};
+	($float: ident; decimals = $value: expr) => {
+		const DECIMALS: Option<usize> = Some($value);
+	};
+	($float: ident; $option: ident = $value: expr) => {
+		compile_error!(concat!("unknown bounded float option `", stringify!($option), "`"));
+	};
+}
+
+/// Shared body of [`bound_f32_impl!`] and [`bound_f64_impl!`].
+///
+/// `$generics` are the (const) generic parameters of the implementing type, they may be empty.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_float_impl {
+	(
+		$float: ident; [$($generics: tt)*] $struct_name: ty, $min: expr, $max: expr;
+		$($option: ident = $value: expr),*
+	) => {
+		impl<$($generics)*> $crate::prelude::BoundedFloat for $struct_name {
+			type Float = $float;
+
+			const MIN: $float = <$struct_name>::MIN;
+			const MAX: $float = <$struct_name>::MAX;
+
+			$($crate::__bound_float_option!($float; $option = $value);)*
+
+			fn new(value: $float) -> Self {
+				return Self::new(value);
+			}
+
+			fn try_new(value: $float) -> Result<Self, $crate::prelude::BoundError> {
+				return Self::try_new(value);
+			}
+
+			fn get(&self) -> $float {
+				return self.inner_value;
+			}
+		}
+
+		#[allow(dead_code)]
+		impl<$($generics)*> $struct_name {
+			/// Fails to compile, once the type is used, if the bounds are NaN or inverted.
+			pub const MIN: $float = {
+				let (min, max): ($float, $float) = ($min, $max);
+				assert!(min <= max, "bounded float requires `MIN <= MAX`, neither being NaN");
+				min
+			};
+			pub const MAX: $float = {
+				let _ = Self::MIN;
+				$max
+			};
+
+			/// Brings `value` into range according to the type's NaN and overflow policies.
+			pub const fn new(value: $float) -> Self {
+				let bounds = $crate::prelude::FloatBounds::new(Self::MIN, Self::MAX);
+				let value = bounds.replace_nan(
+					value,
+					<Self as $crate::prelude::BoundedFloat>::NAN,
+					<Self as $crate::prelude::BoundedFloat>::DEFAULT,
+				);
+				return Self { inner_value: bounds.apply(value, <Self as $crate::prelude::BoundedFloat>::OVERFLOW) };
+			}
+
+			/// Fails, naming the violated bound, if `value` is NaN or out of range, regardless of the type's policies.
+			pub const fn try_new(value: $float) -> Result<Self, $crate::prelude::BoundError> {
+				let bounds = $crate::prelude::FloatBounds::new(Self::MIN, Self::MAX);
+				return match bounds.check(value) {
+					Ok(inner_value) => Ok(Self { inner_value }),
+					Err(err) => Err(err),
+				};
+			}
+
+			/// Panics if `value` is NaN or out of range, regardless of the type's policies.
+			///
+			/// In a const context the panic becomes a compilation error, which the `bounded!` macro relies on.
+			pub const fn new_in_range(value: $float) -> Self {
+				let bounds = $crate::prelude::FloatBounds::new(Self::MIN, Self::MAX);
+				return Self { inner_value: bounds.expect(value) };
+			}
+
+			pub const fn get(&self) -> $float {
+				return self.inner_value;
+			}
+
+			/// Brings `value` into range according to the type's NaN and overflow policies.
+			pub const fn set(&mut self, value: $float) {
+				*self = Self::new(value);
+			}
+
+			/// Fails, naming the violated bound, if `value` is NaN or out of range, regardless of the type's policies.
+			/// `self` is left untouched on failure.
+			pub fn try_set(&mut self, value: $float) -> Result<(), $crate::prelude::BoundError> {
+				*self = Self::try_new(value)?;
+				return Ok(());
+			}
+
+			/// Clamps `value` into range, regardless of the type's overflow policy, returning by how much it overshot:
+			/// positive above `MAX`, negative below `MIN` and zero when in range.
+			///
+			/// NaN is still handled by the type's NaN policy.
+			pub const fn set_reporting_excess(&mut self, value: $float) -> $float {
+				let bounds = $crate::prelude::FloatBounds::new(Self::MIN, Self::MAX);
+				let value = bounds.replace_nan(
+					value,
+					<Self as $crate::prelude::BoundedFloat>::NAN,
+					<Self as $crate::prelude::BoundedFloat>::DEFAULT,
+				);
+				let (inner_value, excess) = bounds.saturate(value);
+				self.inner_value = inner_value;
+				return excess;
+			}
+
+			/// `self` mapped from `MIN..=MAX` to `0.0..=1.0`.
+			pub const fn normalized(&self) -> $float {
+				let bounds = $crate::prelude::FloatBounds::new(Self::MIN, Self::MAX);
+				return bounds.normalize(self.inner_value);
+			}
+
+			/// Maps `t` from `0.0..=1.0` to `MIN..=MAX`, the result is brought into range by the type's policies.
+			pub const fn from_normalized(t: $float) -> Self {
+				let bounds = $crate::prelude::FloatBounds::new(Self::MIN, Self::MAX);
+				return Self::new(bounds.denormalize(t));
+			}
+
+			/// Maps `self` to the same relative position in the range of another bounded type.
+			pub fn remap_to<B: $crate::prelude::BoundedFloat>(&self) -> B {
+				use $crate::prelude::PrimitiveFloat;
+
+				let bounds = $crate::prelude::FloatBounds::new(B::MIN.to_f64(), B::MAX.to_f64());
+				let value = bounds.denormalize(self.normalized() as f64);
+				return B::new(<B::Float as PrimitiveFloat>::from_f64(value));
+			}
+
+			/// Interpolates linearly from `self` to `target`, `t` is not clamped but the result is brought into range by
+			/// the type's policies.
+			pub fn lerp(self, target: Self, t: $float) -> Self {
+				return Self::new(self.inner_value + (target.inner_value - self.inner_value) * t);
+			}
+
+			/// Where `self` lies between `from` and `to`, the inverse of [`Self::lerp`].
+			/// Returns `0.0` when `from` and `to` are equal.
+			pub fn inverse_lerp(self, from: Self, to: Self) -> $float {
+				let range = to.inner_value - from.inner_value;
+				return if range != 0.0 { (self.inner_value - from.inner_value) / range } else { 0.0 };
+			}
+
+			/// Interpolates from `self` to `target` with smoothstep easing, `t` is clamped to `0.0..=1.0`.
+			pub fn smoothstep(self, target: Self, t: $float) -> Self {
+				let t = t.clamp(0.0, 1.0);
+				return self.lerp(target, t * t * (3.0 - 2.0 * t));
+			}
+
+			/// Compares with `other` using the type's `epsilon` and `ulps`, see [`BoundedFloat::margin`].
+			pub fn is_close(&self, other: $float) -> bool {
+				return self.approx_eq_with(other, <Self as $crate::prelude::BoundedFloat>::margin());
+			}
+
+			/// Compares with `other` using `margin` instead of the type's `epsilon` and `ulps`.
+			pub fn approx_eq_with(
+				&self,
+				other: $float,
+				margin: impl Into<<$float as $crate::float_cmp::ApproxEq>::Margin>,
+			) -> bool {
+				return $crate::float_cmp::ApproxEq::approx_eq(self.inner_value, other, margin);
+			}
+
+			/// The key compared and hashed by `Eq`, `Ord` and `Hash`, see [`BoundedFloat::QUANTUM`].
+			pub fn quantised(&self) -> i128 {
+				return Self::quantise(self.inner_value);
+			}
+
+			/// The key a primitive `value` is compared by, whether or not it is in range.
+			pub const fn quantise(value: $float) -> i128 {
+				let bounds = $crate::prelude::FloatBounds::new(Self::MIN, Self::MAX);
+				let quantum = match <Self as $crate::prelude::BoundedFloat>::QUANTUM {
+					Some(quantum) => quantum,
+					None => bounds.default_quantum(),
+				};
+				return bounds.quantise(value, quantum);
+			}
+		}
+
+		$crate::__bound_float_operator!($float; [$($generics)*] $struct_name; Add, add, AddAssign, add_assign, +);
+		$crate::__bound_float_operator!($float; [$($generics)*] $struct_name; Sub, sub, SubAssign, sub_assign, -);
+		$crate::__bound_float_operator!($float; [$($generics)*] $struct_name; Mul, mul, MulAssign, mul_assign, *);
+		$crate::__bound_float_operator!($float; [$($generics)*] $struct_name; Div, div, DivAssign, div_assign, /);
+		$crate::__bound_float_operator!($float; [$($generics)*] $struct_name; Rem, rem, RemAssign, rem_assign, %);
+
+		impl<$($generics)*> std::ops::Neg for $struct_name {
+			type Output = Self;
+
+			fn neg(self) -> Self::Output {
+				return Self::new(-self.inner_value);
+			}
+		}
+
+		impl<'a, $($generics)*> std::ops::Neg for &'a $struct_name {
+			type Output = $struct_name;
+
+			fn neg(self) -> Self::Output {
+				return -*self;
+			}
+		}
+
+		/// Adds the values one by one, like `+` does, an empty iterator sums to `0.0` brought into range.
+		impl<$($generics)*> std::iter::Sum for $struct_name {
+			fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+				return iter.reduce(|sum, value| sum + value).unwrap_or_else(|| Self::new(0.0));
+			}
+		}
+
+		impl<'a, $($generics)*> std::iter::Sum<&'a $struct_name> for $struct_name {
+			fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+				return iter.copied().sum();
+			}
+		}
+
+		/// Multiplies the values one by one, like `*` does, an empty iterator multiplies to `1.0` brought into range.
+		impl<$($generics)*> std::iter::Product for $struct_name {
+			fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+				return iter.reduce(|product, value| product * value).unwrap_or_else(|| Self::new(1.0));
+			}
+		}
+
+		impl<'a, $($generics)*> std::iter::Product<&'a $struct_name> for $struct_name {
+			fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+				return iter.copied().product();
+			}
+		}
+
+		impl<$($generics)*> core::cmp::PartialEq<Self> for $struct_name {
+			fn eq(&self, other: &Self) -> bool {
+				return self.quantised() == other.quantised();
+			}
+		}
+
+		impl<$($generics)*> core::cmp::Eq for $struct_name {}
+
+		impl<$($generics)*> core::hash::Hash for $struct_name {
+			fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+				core::hash::Hash::hash(&self.quantised(), state);
+			}
+		}
+
+		/// Quantised like `==` between bounded values, NaN is never equal.
+		/// The type's `epsilon` and `ulps` are not used, see `is_close` for that.
+		impl<$($generics)*> core::cmp::PartialEq<$float> for $struct_name {
+			fn eq(&self, other: &$float) -> bool {
+				return !other.is_nan() && self.quantised() == Self::quantise(*other);
+			}
+		}
+
+		impl<$($generics)*> core::cmp::PartialEq<$struct_name> for $float {
+			fn eq(&self, other: &$struct_name) -> bool {
+				return other == self;
+			}
+		}
+
+		impl<$($generics)*> $crate::float_cmp::ApproxEq for $struct_name {
+			type Margin = <$float as $crate::float_cmp::ApproxEq>::Margin;
+
+			fn approx_eq<M: Into<Self::Margin>>(self, other: Self, margin: M) -> bool {
+				return self.approx_eq_with(other.inner_value, margin);
+			}
+		}
+
+		impl<$($generics)*> core::cmp::PartialOrd for $struct_name {
+			fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+				return Some(core::cmp::Ord::cmp(self, other));
+			}
+		}
+
+		impl<$($generics)*> core::cmp::Ord for $struct_name {
+			fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+				return self.quantised().cmp(&other.quantised());
+			}
+		}
+
+		/// Quantised like `Ord`, so it agrees with `==`, NaN is unordered.
+		impl<$($generics)*> core::cmp::PartialOrd<$float> for $struct_name {
+			fn partial_cmp(&self, other: &$float) -> Option<std::cmp::Ordering> {
+				return if other.is_nan() { None } else { Some(self.quantised().cmp(&Self::quantise(*other))) };
+			}
+		}
+
+		impl<$($generics)*> core::cmp::PartialOrd<$struct_name> for $float {
+			fn partial_cmp(&self, other: &$struct_name) -> Option<std::cmp::Ordering> {
+				return other.partial_cmp(self).map(std::cmp::Ordering::reverse);
+			}
+		}
+
+		impl<$($generics)*> core::convert::From<$float> for $struct_name {
+			fn from(value: $float) -> Self {
+				return Self::new(value);
+			}
+		}
+
+		impl<$($generics)*> core::default::Default for $struct_name {
+			fn default() -> Self {
+				return Self::new(<Self as $crate::prelude::BoundedFloat>::DEFAULT);
+			}
+		}
+
+		$crate::__bound_float_rand!($float; [$($generics)*] $struct_name);
+		$crate::__bound_float_proptest!($float; [$($generics)*] $struct_name);
+		$crate::__bound_float_quickcheck!($float; [$($generics)*] $struct_name);
+
+		/// Writes the value times `SCALE`, followed by `SUFFIX`, honouring the formatter's width and precision.
+		impl<$($generics)*> std::fmt::Display for $struct_name {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				return $crate::prelude::fmt_bounded(
+					self.inner_value * <Self as $crate::prelude::BoundedFloat>::SCALE,
+					<Self as $crate::prelude::BoundedFloat>::SUFFIX,
+					<Self as $crate::prelude::BoundedFloat>::DECIMALS,
+					f,
+				);
+			}
+		}
+
+		/// Parses a value written by `Display`, failing if it is out of range regardless of the type's policies.
+		impl<$($generics)*> std::str::FromStr for $struct_name {
+			type Err = $crate::prelude::ParseBoundedError;
+
+			fn from_str(text: &str) -> Result<Self, Self::Err> {
+				let value: $float = $crate::prelude::parse_bounded(text, <Self as $crate::prelude::BoundedFloat>::SUFFIX)?;
+				return Self::try_new(value / <Self as $crate::prelude::BoundedFloat>::SCALE)
+					.map_err($crate::prelude::ParseBoundedError::OutOfBounds);
+			}
+		}
+
+		impl<$($generics)*> std::ops::Deref for $struct_name {
+			type Target = $float;
+
+			fn deref(&self) -> &Self::Target {
+				return &self.inner_value;
+			}
+		}
+	};
+}
+
+/// Implements assignment operators, comparisons and conversions between a bounded `$float` and the primitive `$other`,
+/// computing the operators in `$wide`, the wider of the two floats.
+///
+/// The `@operators` form adds the binary operators, returning `$wide`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_float_mixed_impl {
+	(@operators $float: ident, $other: ident => $wide: ident; [$($generics: tt)*] $struct_name: ty) => {
+		$crate::__bound_float_operator!(@mixed $float, $other => $wide; [$($generics)*] $struct_name; Add, add, +);
+		$crate::__bound_float_operator!(@mixed $float, $other => $wide; [$($generics)*] $struct_name; Sub, sub, -);
+		$crate::__bound_float_operator!(@mixed $float, $other => $wide; [$($generics)*] $struct_name; Mul, mul, *);
+		$crate::__bound_float_operator!(@mixed $float, $other => $wide; [$($generics)*] $struct_name; Div, div, /);
+		$crate::__bound_float_operator!(@mixed $float, $other => $wide; [$($generics)*] $struct_name; Rem, rem, %);
+	};
+	($float: ident, $other: ident => $wide: ident; [$($generics: tt)*] $struct_name: ty) => {
+		$crate::__bound_float_operator!(@mixed_assign $float, $other => $wide; [$($generics)*] $struct_name; AddAssign, add_assign, +);
+		$crate::__bound_float_operator!(@mixed_assign $float, $other => $wide; [$($generics)*] $struct_name; SubAssign, sub_assign, -);
+		$crate::__bound_float_operator!(@mixed_assign $float, $other => $wide; [$($generics)*] $struct_name; MulAssign, mul_assign, *);
+		$crate::__bound_float_operator!(@mixed_assign $float, $other => $wide; [$($generics)*] $struct_name; DivAssign, div_assign, /);
+		$crate::__bound_float_operator!(@mixed_assign $float, $other => $wide; [$($generics)*] $struct_name; RemAssign, rem_assign, %);
+
+		/// Quantised like the comparisons with `$float`, after converting `other` to it.
+		impl<$($generics)*> core::cmp::PartialEq<$other> for $struct_name {
+			fn eq(&self, other: &$other) -> bool {
+				return *self == *other as $float;
+			}
+		}
+
+		impl<$($generics)*> core::cmp::PartialEq<$struct_name> for $other {
+			fn eq(&self, other: &$struct_name) -> bool {
+				return other == self;
+			}
+		}
+
+		impl<$($generics)*> core::cmp::PartialOrd<$other> for $struct_name {
+			fn partial_cmp(&self, other: &$other) -> Option<std::cmp::Ordering> {
+				return self.partial_cmp(&(*other as $float));
+			}
+		}
+
+		impl<$($generics)*> core::cmp::PartialOrd<$struct_name> for $other {
+			fn partial_cmp(&self, other: &$struct_name) -> Option<std::cmp::Ordering> {
+				return other.partial_cmp(self).map(std::cmp::Ordering::reverse);
+			}
+		}
+
+		impl<$($generics)*> core::convert::From<$other> for $struct_name {
+			fn from(value: $other) -> Self {
+				return Self::new(value as $float);
+			}
+		}
+	};
+}
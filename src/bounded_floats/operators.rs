@@ -0,0 +1,132 @@
+/// Implements one arithmetic operator for a bounded `$float`, along with its assignment form and the
+/// reference-operand variants of both.
+///
+/// Between two bounded values the result is brought into range like `new` does, with a primitive `$float` operand
+/// the result is the raw `$float`.
+///
+/// The `@mixed` forms implement the same against the primitive `$other`, computing in `$wide`, the wider of the two
+/// floats.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_float_operator {
+	(
+		$float: ident; [$($generics: tt)*] $struct_name: ty;
+		$trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident, $op: tt
+	) => {
+		impl<$($generics)*> std::ops::$trait for $struct_name {
+			type Output = Self;
+
+			fn $method(self, other: Self) -> Self::Output {
+				return Self::new(self.inner_value $op other.inner_value);
+			}
+		}
+
+		impl<$($generics)*> std::ops::$trait<$float> for $struct_name {
+			type Output = $float;
+
+			fn $method(self, other: $float) -> Self::Output {
+				return self.inner_value $op other;
+			}
+		}
+
+		impl<$($generics)*> std::ops::$trait<$struct_name> for $float {
+			type Output = $float;
+
+			fn $method(self, other: $struct_name) -> Self::Output {
+				return self $op other.inner_value;
+			}
+		}
+
+		impl<$($generics)*> std::ops::$assign_trait for $struct_name {
+			fn $assign_method(&mut self, other: Self) {
+				self.set(self.inner_value $op other.inner_value);
+			}
+		}
+
+		impl<$($generics)*> std::ops::$assign_trait<$float> for $struct_name {
+			fn $assign_method(&mut self, other: $float) {
+				self.set(self.inner_value $op other);
+			}
+		}
+
+		$crate::__forward_ref_operator!([$($generics)*] $struct_name, $struct_name; $trait, $method);
+		$crate::__forward_ref_operator!([$($generics)*] $struct_name, $float; $trait, $method);
+		$crate::__forward_ref_operator!([$($generics)*] $float, $struct_name; $trait, $method);
+		$crate::__forward_ref_operator!(@assign [$($generics)*] $struct_name, $struct_name; $assign_trait, $assign_method);
+		$crate::__forward_ref_operator!(@assign [$($generics)*] $struct_name, $float; $assign_trait, $assign_method);
+	};
+	(
+		@mixed $float: ident, $other: ident => $wide: ident; [$($generics: tt)*] $struct_name: ty;
+		$trait: ident, $method: ident, $op: tt
+	) => {
+		impl<$($generics)*> std::ops::$trait<$other> for $struct_name {
+			type Output = $wide;
+
+			fn $method(self, other: $other) -> Self::Output {
+				return self.inner_value as $wide $op other as $wide;
+			}
+		}
+
+		impl<$($generics)*> std::ops::$trait<$struct_name> for $other {
+			type Output = $wide;
+
+			fn $method(self, other: $struct_name) -> Self::Output {
+				return self as $wide $op other.inner_value as $wide;
+			}
+		}
+
+		$crate::__forward_ref_operator!([$($generics)*] $struct_name, $other; $trait, $method);
+		$crate::__forward_ref_operator!([$($generics)*] $other, $struct_name; $trait, $method);
+	};
+	(
+		@mixed_assign $float: ident, $other: ident => $wide: ident; [$($generics: tt)*] $struct_name: ty;
+		$assign_trait: ident, $assign_method: ident, $op: tt
+	) => {
+		impl<$($generics)*> std::ops::$assign_trait<$other> for $struct_name {
+			fn $assign_method(&mut self, other: $other) {
+				self.set((self.inner_value as $wide $op other as $wide) as $float);
+			}
+		}
+
+		$crate::__forward_ref_operator!(@assign [$($generics)*] $struct_name, $other; $assign_trait, $assign_method);
+	};
+}
+
+/// Implements the reference-operand variants of a binary operator, or with `@assign` of an assignment operator,
+/// by copying the operands into the by-value implementation. Shared by the bounded number generators.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __forward_ref_operator {
+	([$($generics: tt)*] $lhs: ty, $rhs: ty; $trait: ident, $method: ident) => {
+		impl<'a, $($generics)*> std::ops::$trait<&'a $rhs> for $lhs {
+			type Output = <$lhs as std::ops::$trait<$rhs>>::Output;
+
+			fn $method(self, other: &'a $rhs) -> Self::Output {
+				return std::ops::$trait::$method(self, *other);
+			}
+		}
+
+		impl<'a, $($generics)*> std::ops::$trait<$rhs> for &'a $lhs {
+			type Output = <$lhs as std::ops::$trait<$rhs>>::Output;
+
+			fn $method(self, other: $rhs) -> Self::Output {
+				return std::ops::$trait::$method(*self, other);
+			}
+		}
+
+		impl<'a, 'b, $($generics)*> std::ops::$trait<&'b $rhs> for &'a $lhs {
+			type Output = <$lhs as std::ops::$trait<$rhs>>::Output;
+
+			fn $method(self, other: &'b $rhs) -> Self::Output {
+				return std::ops::$trait::$method(*self, *other);
+			}
+		}
+	};
+	(@assign [$($generics: tt)*] $lhs: ty, $rhs: ty; $assign_trait: ident, $assign_method: ident) => {
+		impl<'a, $($generics)*> std::ops::$assign_trait<&'a $rhs> for $lhs {
+			fn $assign_method(&mut self, other: &'a $rhs) {
+				std::ops::$assign_trait::$assign_method(self, *other);
+			}
+		}
+	};
+}
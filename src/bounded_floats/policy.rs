@@ -0,0 +1,267 @@
+use std::fmt::{self, Display};
+
+/// How a bounded float brings a value outside of `MIN..=MAX` back into range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverflowPolicy {
+	/// Clamps to the nearest bound.
+	#[default]
+	Clamp,
+	/// Wraps around the range, like angles do: `MAX` itself wraps to `MIN`.
+	Wrap,
+	/// Panics, naming the bound that was violated.
+	Reject,
+}
+
+/// What a bounded float does when given NaN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NanPolicy {
+	/// Panics.
+	Reject,
+	/// Replaces NaN with `MIN`.
+	#[default]
+	Min,
+	/// Replaces NaN with the type's default value.
+	Default,
+	/// Panics in debug builds, replaces NaN with `MIN` in release builds.
+	DebugPanic,
+}
+
+/// The bound violated by a value, see [`FloatBounds::check`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoundError {
+	BelowMin { value: f64, min: f64 },
+	AboveMax { value: f64, max: f64 },
+	NaN,
+}
+
+impl Display for BoundError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			BoundError::BelowMin { value, min } => write!(f, "value `{value}` is below the minimum of `{min}`"),
+			BoundError::AboveMax { value, max } => write!(f, "value `{value}` is above the maximum of `{max}`"),
+			BoundError::NaN => write!(f, "value is NaN"),
+		}
+	}
+}
+
+impl std::error::Error for BoundError {}
+
+impl BoundError {
+	/// The violated bound without the values, usable as a const panic message.
+	pub const fn summary(self) -> &'static str {
+		match self {
+			BoundError::BelowMin { .. } => "value is below the minimum",
+			BoundError::AboveMax { .. } => "value is above the maximum",
+			BoundError::NaN => "value is NaN",
+		}
+	}
+}
+
+/// The inclusive range `min..=max` of a bounded float.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatBounds<F> {
+	pub min: F,
+	pub max: F,
+}
+
+impl<F> FloatBounds<F> {
+	pub const fn new(min: F, max: F) -> Self { FloatBounds { min, max } }
+}
+
+macro_rules! impl_float_bounds {
+	($float: ident) => {
+		impl FloatBounds<$float> {
+			/// Brings `value` into range according to `policy`.
+			///
+			/// # Panics
+			/// If `policy` is [`OverflowPolicy::Reject`] and `value` is out of range.
+			pub const fn apply(self, value: $float, policy: OverflowPolicy) -> $float {
+				match policy {
+					OverflowPolicy::Clamp => self.clamp(value),
+					OverflowPolicy::Wrap => self.wrap(value),
+					OverflowPolicy::Reject => self.expect(value),
+				}
+			}
+
+			/// Returns `value` untouched if it is in range.
+			///
+			/// # Panics
+			/// If `value` is NaN or out of range, which fails compilation when evaluated in a const context.
+			pub const fn expect(self, value: $float) -> $float {
+				match self.check(value) {
+					Ok(value) => value,
+					Err(err) => panic!("{}", err.summary()),
+				}
+			}
+
+			/// Replaces NaN according to `policy`, any other value is returned untouched.
+			///
+			/// # Panics
+			/// If `value` is NaN and `policy` is [`NanPolicy::Reject`], or [`NanPolicy::DebugPanic`] in debug builds.
+			pub const fn replace_nan(self, value: $float, policy: NanPolicy, default: $float) -> $float {
+				if !value.is_nan() {
+					return value;
+				}
+
+				match policy {
+					NanPolicy::Reject => panic!("{}", BoundError::NaN.summary()),
+					NanPolicy::Min => self.min,
+					NanPolicy::Default => default,
+					NanPolicy::DebugPanic => {
+						if cfg!(debug_assertions) {
+							panic!("{}", BoundError::NaN.summary());
+						}
+
+						self.min
+					}
+				}
+			}
+
+			/// Returns `value` untouched if it is in range, otherwise the bound it violates.
+			pub const fn check(self, value: $float) -> Result<$float, BoundError> {
+				if value.is_nan() {
+					Err(BoundError::NaN)
+				} else if value < self.min {
+					Err(BoundError::BelowMin { value: value as f64, min: self.min as f64 })
+				} else if value > self.max {
+					Err(BoundError::AboveMax { value: value as f64, max: self.max as f64 })
+				} else {
+					Ok(value)
+				}
+			}
+
+			pub const fn clamp(self, value: $float) -> $float { value.clamp(self.min, self.max) }
+
+			/// Wraps `value` around the range, `max` itself wraps to `min`.
+			///
+			/// Infinities cannot be wrapped and are clamped instead.
+			pub const fn wrap(self, value: $float) -> $float {
+				let range = self.max - self.min;
+				if value.is_infinite() {
+					self.clamp(value)
+				} else if range > 0.0 {
+					// `rem_euclid` is not const
+					let offset = (value - self.min) % range;
+					self.min + if offset < 0.0 { offset + range } else { offset }
+				} else {
+					self.min
+				}
+			}
+
+			/// Maps `value` from `min..=max` to `0.0..=1.0`, an empty range maps everything to `0.0`.
+			pub const fn normalize(self, value: $float) -> $float {
+				let range = self.max - self.min;
+				if range > 0.0 { (value - self.min) / range } else { 0.0 }
+			}
+
+			/// Maps `t` from `0.0..=1.0` to `min..=max`, the inverse of [`Self::normalize`].
+			pub const fn denormalize(self, t: $float) -> $float { self.min + (self.max - self.min) * t }
+
+			/// Four units of least precision at the largest magnitude in range,
+			/// the smallest step that still absorbs rounding errors anywhere in range.
+			/// `EPSILON` if both bounds are zero or a bound is infinite.
+			pub const fn default_quantum(self) -> $float {
+				let magnitude = self.min.abs().max(self.max.abs());
+				if magnitude > 0.0 && magnitude.is_finite() { magnitude * $float::EPSILON * 4.0 } else { $float::EPSILON }
+			}
+
+			/// Rounds `value` to the nearest multiple of `quantum` above `min`, saturating at the bounds of `i128`.
+			pub const fn quantise(self, value: $float, quantum: $float) -> i128 {
+				((value - self.min) / quantum).round() as i128
+			}
+
+			/// Clamps `value`, also returning by how much it overshot:
+			/// positive above `max`, negative below `min` and zero when in range.
+			pub const fn saturate(self, value: $float) -> ($float, $float) {
+				let clamped = self.clamp(value);
+				(clamped, value - clamped)
+			}
+		}
+	};
+}
+
+impl_float_bounds!(f32);
+impl_float_bounds!(f64);
+
+#[cfg(test)]
+mod tests {
+	use float_cmp::approx_eq;
+
+	use super::*;
+
+	const BOUNDS: FloatBounds<f32> = FloatBounds::new(0.0, 360.0);
+
+	#[test]
+	fn clamp_policy_clamps_to_nearest_bound() {
+		assert!(approx_eq!(f32, BOUNDS.apply(400.0, OverflowPolicy::Clamp), 360.0));
+		assert!(approx_eq!(f32, BOUNDS.apply(-10.0, OverflowPolicy::Clamp), 0.0));
+		assert!(approx_eq!(f32, BOUNDS.apply(10.0, OverflowPolicy::Clamp), 10.0));
+	}
+
+	#[test]
+	fn wrap_policy_wraps_around_range() {
+		assert!(approx_eq!(f32, BOUNDS.apply(400.0, OverflowPolicy::Wrap), 40.0));
+		assert!(approx_eq!(f32, BOUNDS.apply(-10.0, OverflowPolicy::Wrap), 350.0));
+		assert!(approx_eq!(f32, BOUNDS.apply(360.0, OverflowPolicy::Wrap), 0.0));
+		assert!(approx_eq!(f64, FloatBounds::new(1.0f64, 1.0).wrap(5.0), 1.0));
+		assert!(approx_eq!(f32, BOUNDS.wrap(f32::INFINITY), 360.0));
+		assert!(approx_eq!(f32, BOUNDS.wrap(f32::NEG_INFINITY), 0.0));
+	}
+
+	#[test]
+	fn nan_policy_replaces_nan() {
+		assert!(approx_eq!(f32, BOUNDS.replace_nan(f32::NAN, NanPolicy::Min, 90.0), 0.0));
+		assert!(approx_eq!(f32, BOUNDS.replace_nan(f32::NAN, NanPolicy::Default, 90.0), 90.0));
+		assert!(approx_eq!(f32, BOUNDS.replace_nan(45.0, NanPolicy::Reject, 90.0), 45.0));
+	}
+
+	#[test]
+	#[should_panic(expected = "NaN")]
+	fn reject_nan_policy_panics() {
+		BOUNDS.replace_nan(f32::NAN, NanPolicy::Reject, 90.0);
+	}
+
+	#[test]
+	#[cfg_attr(debug_assertions, should_panic(expected = "NaN"))]
+	fn debug_panic_nan_policy_panics_only_in_debug() {
+		assert!(approx_eq!(f32, BOUNDS.replace_nan(f32::NAN, NanPolicy::DebugPanic, 90.0), 0.0));
+	}
+
+	#[test]
+	#[should_panic(expected = "above the maximum")]
+	fn reject_policy_panics_when_out_of_range() {
+		BOUNDS.apply(361.0, OverflowPolicy::Reject);
+	}
+
+	#[test]
+	fn check_reports_violated_bound() {
+		assert_eq!(BOUNDS.check(-1.0), Err(BoundError::BelowMin { value: -1.0, min: 0.0 }));
+		assert_eq!(BOUNDS.check(361.0), Err(BoundError::AboveMax { value: 361.0, max: 360.0 }));
+		assert_eq!(BOUNDS.check(360.0), Ok(360.0));
+		assert_eq!(BOUNDS.check(f32::NAN), Err(BoundError::NaN));
+		assert!(BOUNDS.check(f32::INFINITY).is_err());
+	}
+
+	#[test]
+	fn normalize_maps_to_unit_range() {
+		assert!(approx_eq!(f32, BOUNDS.normalize(90.0), 0.25));
+		assert!(approx_eq!(f32, BOUNDS.denormalize(0.25), 90.0));
+		assert!(approx_eq!(f64, FloatBounds::new(-1.0f64, 1.0).normalize(0.0), 0.5));
+		assert!(approx_eq!(f64, FloatBounds::new(1.0f64, 1.0).normalize(5.0), 0.0));
+	}
+
+	#[test]
+	fn quantise_rounds_to_nearest_multiple() {
+		assert_eq!(BOUNDS.quantise(0.0, 0.5), 0);
+		assert_eq!(BOUNDS.quantise(10.2, 0.5), 20);
+		assert_eq!(BOUNDS.quantise(10.3, 0.5), 21);
+		assert_eq!(FloatBounds::new(-1.0f64, 1.0).quantise(-1.0, 0.1), 0);
+	}
+
+	#[test]
+	fn saturate_reports_excess() {
+		assert_eq!(BOUNDS.saturate(370.0), (360.0, 10.0));
+		assert_eq!(BOUNDS.saturate(-5.0), (0.0, -5.0));
+		assert_eq!(BOUNDS.saturate(5.0), (5.0, 0.0));
+	}
+}
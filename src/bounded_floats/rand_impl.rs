@@ -0,0 +1,27 @@
+/// Samples a bounded float uniformly over `MIN..=MAX` with `StandardUniform`,
+/// and between two values of the type with `random_range`.
+///
+/// Expands to nothing when the `rand` feature is disabled.
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_float_rand {
+	($float: ident; [$($generics: tt)*] $struct_name: ty) => {
+		impl<$($generics)*> $crate::rand::distr::Distribution<$struct_name> for $crate::rand::distr::StandardUniform {
+			fn sample<R: $crate::rand::Rng + ?Sized>(&self, rng: &mut R) -> $struct_name {
+				return $crate::prelude::random_bounded(rng);
+			}
+		}
+
+		impl<$($generics)*> $crate::rand::distr::uniform::SampleUniform for $struct_name {
+			type Sampler = $crate::prelude::UniformBounded<$struct_name>;
+		}
+	};
+}
+
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_float_rand {
+	($($tokens: tt)*) => {};
+}
@@ -0,0 +1,407 @@
+use std::{
+	cmp::Ordering,
+	fmt::{self, Display},
+	ops::Deref,
+};
+
+use float_cmp::{ApproxEq, F32Margin, F64Margin};
+
+use super::{BoundError, BoundedFloat, FloatBounds, PrimitiveFloat, fmt_bounded};
+
+/// Bounds given to a [`RangedF32`] or [`RangedF64`] where `min` is above `max`, or either is NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidBounds {
+	pub min: f64,
+	pub max: f64,
+}
+
+impl Display for InvalidBounds {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "bounds `{}..={}` are inverted or NaN", self.min, self.max)
+	}
+}
+
+impl std::error::Error for InvalidBounds {}
+
+/// Why [`RangedF32::try_new`] or [`RangedF64::try_new`] failed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RangedError {
+	InvalidBounds(InvalidBounds),
+	OutOfBounds(BoundError),
+}
+
+impl Display for RangedError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			RangedError::InvalidBounds(err) => err.fmt(f),
+			RangedError::OutOfBounds(err) => err.fmt(f),
+		}
+	}
+}
+
+impl std::error::Error for RangedError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			RangedError::InvalidBounds(err) => Some(err),
+			RangedError::OutOfBounds(err) => Some(err),
+		}
+	}
+}
+
+impl From<InvalidBounds> for RangedError {
+	fn from(err: InvalidBounds) -> Self { RangedError::InvalidBounds(err) }
+}
+
+impl From<BoundError> for RangedError {
+	fn from(err: BoundError) -> Self { RangedError::OutOfBounds(err) }
+}
+
+/// How ranged floats are serialized, their value next to their bounds.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RangedRepr<F> {
+	value: F,
+	min: F,
+	max: F,
+}
+
+macro_rules! impl_ranged {
+	($name: ident, $float: ident, $margin: ident, $repr: literal) => {
+		/// A float within `min..=max`, where the bounds are stored alongside the value instead of being constants,
+		/// so that they can come from data files.
+		///
+		/// Out of range values are clamped, NaN is replaced with `min`. Arithmetic between two ranged values keeps the
+		/// bounds of the left operand, with a primitive operand it returns the primitive.
+		///
+		/// With the `serde` feature, it serializes as `{ value, min, max }`.
+		/// Deserialization fails on invalid bounds and clamps the value.
+		///
+		/// Some traits of the bounded floats are left out on purpose:
+		/// - `Eq`, `Ord` and `Hash`, since equality is approximate and therefore not transitive.
+		/// - `Sum` and `Product` into `Self`, since an empty iterator has no bounds to give the result.
+		///   They are implemented into the primitive float instead.
+		/// - `FromStr`, since the text written by `Display` holds only the value, not the bounds.
+		#[derive(Debug, Clone, Copy)]
+		#[cfg_attr(
+			feature = "serde",
+			derive(serde::Serialize, serde::Deserialize),
+			serde(try_from = $repr, into = $repr)
+		)]
+		pub struct $name {
+			inner_value: $float,
+			bounds: FloatBounds<$float>,
+		}
+
+		impl $name {
+			/// Clamps `value` into `min..=max`.
+			///
+			/// # Panics
+			/// If `min` is above `max`, or either is NaN.
+			pub fn new(value: $float, min: $float, max: $float) -> Self {
+				let bounds = Self::checked_bounds(min, max).unwrap_or_else(|err| panic!("{err}"));
+				$name { inner_value: Self::fit(bounds, value), bounds }
+			}
+
+			/// Like [`Self::new`], but fails instead of panicking on invalid bounds,
+			/// and instead of clamping when `value` is NaN or out of range.
+			pub fn try_new(value: $float, min: $float, max: $float) -> Result<Self, RangedError> {
+				let bounds = Self::checked_bounds(min, max)?;
+				let inner_value = bounds.check(value)?;
+				Ok($name { inner_value, bounds })
+			}
+
+			/// Takes the value and bounds of a macro-generated bounded float.
+			pub fn from_bounded<B: BoundedFloat>(bounded: B) -> Self {
+				let [value, min, max] = [bounded.get(), B::MIN, B::MAX].map(|float| float.to_f64() as $float);
+				Self::new(value, min, max)
+			}
+
+			/// Clamps `value` into the bounds of `self`.
+			pub fn with_value(self, value: $float) -> Self { $name { inner_value: Self::fit(self.bounds, value), ..self } }
+
+			pub fn get(&self) -> $float { self.inner_value }
+			pub fn min(&self) -> $float { self.bounds.min }
+			pub fn max(&self) -> $float { self.bounds.max }
+			pub fn bounds(&self) -> FloatBounds<$float> { self.bounds }
+
+			/// Clamps `value` into range before storing it.
+			pub fn set(&mut self, value: $float) { self.inner_value = Self::fit(self.bounds, value); }
+
+			/// Stores `value` only if it is in range.
+			pub fn try_set(&mut self, value: $float) -> Result<(), BoundError> {
+				self.inner_value = self.bounds.check(value)?;
+				Ok(())
+			}
+
+			/// Changes the bounds, clamping the value into them.
+			///
+			/// # Panics
+			/// If `min` is above `max`, or either is NaN.
+			pub fn set_bounds(&mut self, min: $float, max: $float) {
+				if let Err(err) = self.try_set_bounds(min, max) {
+					panic!("{err}");
+				}
+			}
+
+			/// Changes the bounds, clamping the value into them, unless they are invalid.
+			pub fn try_set_bounds(&mut self, min: $float, max: $float) -> Result<(), InvalidBounds> {
+				self.bounds = Self::checked_bounds(min, max)?;
+				self.inner_value = self.bounds.clamp(self.inner_value);
+				Ok(())
+			}
+
+			/// Maps the value from `min..=max` to `0.0..=1.0`, an empty range maps to `0.0`.
+			pub fn normalized(&self) -> $float { self.bounds.normalize(self.inner_value) }
+
+			/// Sets the value from `t` in `0.0..=1.0`, the inverse of [`Self::normalized`].
+			pub fn set_normalized(&mut self, t: $float) { self.set(self.bounds.denormalize(t)); }
+
+			fn fit(bounds: FloatBounds<$float>, value: $float) -> $float {
+				if value.is_nan() { bounds.min } else { bounds.clamp(value) }
+			}
+
+			fn checked_bounds(min: $float, max: $float) -> Result<FloatBounds<$float>, InvalidBounds> {
+				if min <= max {
+					Ok(FloatBounds::new(min, max))
+				} else {
+					Err(InvalidBounds { min: min as f64, max: max as f64 })
+				}
+			}
+		}
+
+		impl_ranged!(@operator $name, $float; Add, add, AddAssign, add_assign, +);
+		impl_ranged!(@operator $name, $float; Sub, sub, SubAssign, sub_assign, -);
+		impl_ranged!(@operator $name, $float; Mul, mul, MulAssign, mul_assign, *);
+		impl_ranged!(@operator $name, $float; Div, div, DivAssign, div_assign, /);
+		impl_ranged!(@operator $name, $float; Rem, rem, RemAssign, rem_assign, %);
+
+		impl std::ops::Neg for $name {
+			type Output = Self;
+
+			fn neg(self) -> Self::Output { self.with_value(-self.inner_value) }
+		}
+
+		impl std::ops::Neg for &$name {
+			type Output = $name;
+
+			fn neg(self) -> Self::Output { -*self }
+		}
+
+		/// Compares the values only, approximately, ignoring the bounds.
+		impl PartialEq for $name {
+			fn eq(&self, other: &Self) -> bool { self.inner_value.approx_eq(other.inner_value, $margin::default()) }
+		}
+
+		impl PartialEq<$float> for $name {
+			fn eq(&self, other: &$float) -> bool { self.inner_value.approx_eq(*other, $margin::default()) }
+		}
+
+		impl PartialEq<$name> for $float {
+			fn eq(&self, other: &$name) -> bool { other == self }
+		}
+
+		impl ApproxEq for $name {
+			type Margin = $margin;
+
+			fn approx_eq<M: Into<Self::Margin>>(self, other: Self, margin: M) -> bool {
+				self.inner_value.approx_eq(other.inner_value, margin)
+			}
+		}
+
+		impl PartialOrd for $name {
+			fn partial_cmp(&self, other: &Self) -> Option<Ordering> { self.partial_cmp(&other.inner_value) }
+		}
+
+		impl PartialOrd<$float> for $name {
+			fn partial_cmp(&self, other: &$float) -> Option<Ordering> {
+				if self == other { Some(Ordering::Equal) } else { self.inner_value.partial_cmp(other) }
+			}
+		}
+
+		impl PartialOrd<$name> for $float {
+			fn partial_cmp(&self, other: &$name) -> Option<Ordering> { other.partial_cmp(self).map(Ordering::reverse) }
+		}
+
+		impl std::iter::Sum<$name> for $float {
+			fn sum<I: Iterator<Item = $name>>(iter: I) -> Self { iter.map(|ranged| ranged.inner_value).sum() }
+		}
+
+		impl<'a> std::iter::Sum<&'a $name> for $float {
+			fn sum<I: Iterator<Item = &'a $name>>(iter: I) -> Self { iter.copied().sum() }
+		}
+
+		impl std::iter::Product<$name> for $float {
+			fn product<I: Iterator<Item = $name>>(iter: I) -> Self { iter.map(|ranged| ranged.inner_value).product() }
+		}
+
+		impl<'a> std::iter::Product<&'a $name> for $float {
+			fn product<I: Iterator<Item = &'a $name>>(iter: I) -> Self { iter.copied().product() }
+		}
+
+		impl Display for $name {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_bounded(self.inner_value, "", None, f) }
+		}
+
+		impl Deref for $name {
+			type Target = $float;
+
+			fn deref(&self) -> &Self::Target { &self.inner_value }
+		}
+
+		#[cfg(feature = "serde")]
+		impl From<$name> for RangedRepr<$float> {
+			fn from(ranged: $name) -> Self { RangedRepr { value: ranged.inner_value, min: ranged.min(), max: ranged.max() } }
+		}
+
+		#[cfg(feature = "serde")]
+		impl TryFrom<RangedRepr<$float>> for $name {
+			type Error = InvalidBounds;
+
+			fn try_from(repr: RangedRepr<$float>) -> Result<Self, Self::Error> {
+				let bounds = Self::checked_bounds(repr.min, repr.max)?;
+				Ok($name { inner_value: Self::fit(bounds, repr.value), bounds })
+			}
+		}
+	};
+	(@operator $name: ident, $float: ident; $trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident, $op: tt) => {
+		impl std::ops::$trait for $name {
+			type Output = Self;
+
+			fn $method(self, other: Self) -> Self::Output { self.with_value(self.inner_value $op other.inner_value) }
+		}
+
+		impl std::ops::$trait<$float> for $name {
+			type Output = $float;
+
+			fn $method(self, other: $float) -> Self::Output { self.inner_value $op other }
+		}
+
+		impl std::ops::$trait<$name> for $float {
+			type Output = $float;
+
+			fn $method(self, other: $name) -> Self::Output { self $op other.inner_value }
+		}
+
+		impl std::ops::$assign_trait for $name {
+			fn $assign_method(&mut self, other: Self) { self.set(self.inner_value $op other.inner_value); }
+		}
+
+		impl std::ops::$assign_trait<$float> for $name {
+			fn $assign_method(&mut self, other: $float) { self.set(self.inner_value $op other); }
+		}
+
+		crate::__forward_ref_operator!([] $name, $name; $trait, $method);
+		crate::__forward_ref_operator!([] $name, $float; $trait, $method);
+		crate::__forward_ref_operator!([] $float, $name; $trait, $method);
+		crate::__forward_ref_operator!(@assign [] $name, $name; $assign_trait, $assign_method);
+		crate::__forward_ref_operator!(@assign [] $name, $float; $assign_trait, $assign_method);
+	};
+}
+
+impl_ranged!(RangedF32, f32, F32Margin, "RangedRepr<f32>");
+impl_ranged!(RangedF64, f64, F64Margin, "RangedRepr<f64>");
+
+crate::__bound_float_operator!(@mixed_assign f32, f64 => f64; [] RangedF32; AddAssign, add_assign, +);
+crate::__bound_float_operator!(@mixed_assign f32, f64 => f64; [] RangedF32; SubAssign, sub_assign, -);
+crate::__bound_float_operator!(@mixed_assign f32, f64 => f64; [] RangedF32; MulAssign, mul_assign, *);
+crate::__bound_float_operator!(@mixed_assign f32, f64 => f64; [] RangedF32; DivAssign, div_assign, /);
+crate::__bound_float_operator!(@mixed_assign f32, f64 => f64; [] RangedF32; RemAssign, rem_assign, %);
+
+crate::__bound_float_operator!(@mixed_assign f64, f32 => f64; [] RangedF64; AddAssign, add_assign, +);
+crate::__bound_float_operator!(@mixed_assign f64, f32 => f64; [] RangedF64; SubAssign, sub_assign, -);
+crate::__bound_float_operator!(@mixed_assign f64, f32 => f64; [] RangedF64; MulAssign, mul_assign, *);
+crate::__bound_float_operator!(@mixed_assign f64, f32 => f64; [] RangedF64; DivAssign, div_assign, /);
+crate::__bound_float_operator!(@mixed_assign f64, f32 => f64; [] RangedF64; RemAssign, rem_assign, %);
+
+crate::__bound_float_operator!(@mixed f64, f32 => f64; [] RangedF64; Add, add, +);
+crate::__bound_float_operator!(@mixed f64, f32 => f64; [] RangedF64; Sub, sub, -);
+crate::__bound_float_operator!(@mixed f64, f32 => f64; [] RangedF64; Mul, mul, *);
+crate::__bound_float_operator!(@mixed f64, f32 => f64; [] RangedF64; Div, div, /);
+crate::__bound_float_operator!(@mixed f64, f32 => f64; [] RangedF64; Rem, rem, %);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::Ratio;
+
+	#[test]
+	fn clamps_into_stored_bounds() {
+		assert_eq!(RangedF32::new(15.0, 0.0, 10.0), 10.0);
+		assert_eq!(RangedF64::new(-5.0, 0.0, 10.0), 0.0);
+		assert_eq!(RangedF64::new(f64::NAN, 2.0, 10.0), 2.0);
+		assert_eq!(RangedF64::new(5.0, 5.0, 5.0), 5.0);
+		assert_eq!(RangedF32::try_new(11.0, 0.0, 10.0), Err(RangedError::OutOfBounds(BoundError::AboveMax { value: 11.0, max: 10.0 })));
+		assert_eq!(RangedF64::try_new(5.0, 10.0, 0.0), Err(RangedError::InvalidBounds(InvalidBounds { min: 10.0, max: 0.0 })));
+		assert!(RangedF32::try_new(1.0, f32::NAN, 10.0).is_err());
+		assert_eq!(RangedF64::from_bounded(Ratio::new(0.25)).max(), 1.0);
+	}
+
+	#[test]
+	#[should_panic(expected = "inverted or NaN")]
+	fn rejects_inverted_bounds() {
+		let (min, max) = (10.0, 0.0);
+		RangedF64::new(5.0, min, max);
+	}
+
+	#[test]
+	fn reclamps_when_bounds_change() {
+		let mut speed = RangedF64::new(8.0, 0.0, 10.0);
+		speed.set_bounds(0.0, 5.0);
+		assert_eq!(speed, 5.0);
+		speed.set_bounds(0.0, 20.0);
+		assert_eq!(speed, 5.0);
+		assert!(speed.try_set_bounds(1.0, f64::NAN).is_err());
+		assert_eq!(speed.try_set_bounds(3.0, 2.0), Err(InvalidBounds { min: 3.0, max: 2.0 }));
+		assert_eq!(speed.max(), 20.0);
+	}
+
+	#[test]
+	fn operates_like_bounded_floats() {
+		let a = RangedF32::new(6.0, 0.0, 10.0);
+		let b = RangedF32::new(7.0, -100.0, 100.0);
+		assert_eq!(a + b, 10.0);
+		assert_eq!((a + b).min(), 0.0);
+		assert_eq!(b + a, 13.0);
+		assert_eq!(a + 7.0, 13.0);
+		assert_eq!(7.0 - a, 1.0);
+		assert_eq!(-a, 0.0);
+		assert_eq!(a % 4.0, 2.0);
+
+		let mut c = a;
+		c *= 3.0;
+		assert_eq!(c, 10.0);
+		c -= b;
+		assert_eq!(c, 3.0);
+		assert!(a < b && a > 5.0 && 5.0 < a);
+
+		c += 0.5f64;
+		assert_eq!(c, 3.5);
+
+		let mut wide = RangedF64::new(0.5, 0.0, 1.0);
+		assert_eq!(wide + 0.25f32, 0.75);
+		assert_eq!(wide.to_string(), "0.5");
+		assert_eq!(format!("{:.2}", wide), "0.50");
+		assert_eq!(wide.normalized(), 0.5);
+		wide -= 2.0f32;
+		assert_eq!(wide, 0.0);
+
+		let values = [a, b, c];
+		assert_eq!(values.iter().sum::<f32>(), 16.5);
+		assert_eq!(values.into_iter().product::<f32>(), 147.0);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serializes_with_bounds() {
+		let range = RangedF32::new(2.5, 0.0, 10.0);
+		let text = ron::to_string(&range).unwrap();
+		assert_eq!(text, "(value:2.5,min:0.0,max:10.0)");
+
+		let parsed: RangedF32 = ron::from_str(&text).unwrap();
+		assert_eq!((parsed.get(), parsed.min(), parsed.max()), (2.5, 0.0, 10.0));
+
+		let clamped: RangedF32 = ron::from_str("(value: 12.0, min: 0.0, max: 10.0)").unwrap();
+		assert_eq!(clamped, 10.0);
+		assert!(ron::from_str::<RangedF32>("(value: 1.0, min: 5.0, max: 0.0)").is_err());
+	}
+}
@@ -0,0 +1,252 @@
+use super::FloatBounds;
+
+/// The part of a change that did not fit into a [`ResourceF32`] or [`ResourceF64`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResourceEvent<F> {
+	/// The change would have exceeded `max` by `excess`.
+	Overflow { excess: F },
+	/// The change would have gone below `0.0` by `deficit`.
+	Underflow { deficit: F },
+}
+
+/// How resources are deserialized, validated through `new` afterwards.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ResourceRepr<F> {
+	current: F,
+	max: F,
+	rate: F,
+}
+
+macro_rules! impl_resource {
+	($name: ident, $float: ident, $repr: literal) => {
+		/// A current value within `0.0..=max`, where `max` can change at runtime, such as health, stamina or mana.
+		///
+		/// The value changes by `rate` per second on [`Self::tick`], regenerating when positive and decaying when negative.
+		/// Changes that would leave `0.0..=max` are clamped, reporting what was cut off as a [`ResourceEvent`].
+		///
+		/// With the `serde` feature, deserialization clamps like [`Self::new`].
+		#[derive(Debug, Clone, Copy, PartialEq, Default)]
+		#[cfg_attr(
+			feature = "serde",
+			derive(serde::Serialize, serde::Deserialize),
+			serde(from = $repr)
+		)]
+		pub struct $name {
+			current: $float,
+			max: $float,
+			rate: $float,
+		}
+
+		impl $name {
+			/// A resource at `current`, clamped into `0.0..=max`. Negative or NaN `max` becomes `0.0`.
+			pub const fn new(current: $float, max: $float) -> Self {
+				let max = if max > 0.0 { max } else { 0.0 };
+				let current = if current.is_nan() { 0.0 } else { current };
+				$name { current: current.clamp(0.0, max), max, rate: 0.0 }
+			}
+
+			/// A resource at its `max`.
+			pub const fn full(max: $float) -> Self {
+				let resource = Self::new(0.0, max);
+				$name { current: resource.max, ..resource }
+			}
+
+			/// A resource at `0.0`.
+			pub const fn empty(max: $float) -> Self { Self::new(0.0, max) }
+
+			/// Sets the change per second, negative values decay the resource.
+			pub const fn with_rate(mut self, rate: $float) -> Self {
+				self.rate = rate;
+				self
+			}
+
+			pub const fn current(&self) -> $float { self.current }
+			pub const fn max(&self) -> $float { self.max }
+			pub const fn rate(&self) -> $float { self.rate }
+
+			pub const fn set_rate(&mut self, rate: $float) { self.rate = rate; }
+
+			/// `current / max`, `0.0` when `max` is `0.0`.
+			pub const fn ratio(&self) -> $float { self.bounds().normalize(self.current) }
+
+			pub fn is_full(&self) -> bool { self.current >= self.max }
+			pub fn is_empty(&self) -> bool { self.current <= 0.0 }
+
+			/// Adds `amount`, which may be negative.
+			pub fn add(&mut self, amount: $float) -> Option<ResourceEvent<$float>> { self.set_current(self.current + amount) }
+
+			/// Adds `ratio` times `max`, `0.1` restores 10% of the maximum and `-0.1` removes it.
+			pub fn add_ratio(&mut self, ratio: $float) -> Option<ResourceEvent<$float>> { self.add(self.max * ratio) }
+
+			/// Subtracts `amount` only if there is enough of it, returning whether it was spent.
+			///
+			/// Negative or NaN amounts are never spent.
+			pub fn try_spend(&mut self, amount: $float) -> bool {
+				if amount >= 0.0 && amount <= self.current {
+					self.current -= amount;
+					true
+				} else {
+					false
+				}
+			}
+
+			/// Moves the resource forward by `delta_seconds`, applying `rate`.
+			pub fn tick(&mut self, delta_seconds: $float) -> Option<ResourceEvent<$float>> {
+				self.add(self.rate * delta_seconds)
+			}
+
+			/// Sets the current value, clamping it into `0.0..=max`. NaN is ignored.
+			pub fn set_current(&mut self, value: $float) -> Option<ResourceEvent<$float>> {
+				if value.is_nan() {
+					return None;
+				}
+
+				let (current, excess) = self.bounds().saturate(value);
+				self.current = current;
+				if excess > 0.0 {
+					Some(ResourceEvent::Overflow { excess })
+				} else if excess < 0.0 {
+					Some(ResourceEvent::Underflow { deficit: -excess })
+				} else {
+					None
+				}
+			}
+
+			pub fn fill(&mut self) { self.current = self.max; }
+			pub fn deplete(&mut self) { self.current = 0.0; }
+
+			/// Changes `max`, scaling the current value so that [`Self::ratio`] stays the same.
+			///
+			/// A resource with a `max` of `0.0` has no ratio to preserve, and stays empty.
+			pub fn set_max(&mut self, max: $float) {
+				let ratio = self.ratio();
+				*self = Self::new(ratio * max, max).with_rate(self.rate);
+			}
+
+			/// Changes `max`, keeping the current value unless it no longer fits.
+			pub fn set_max_keep_current(&mut self, max: $float) -> Option<ResourceEvent<$float>> {
+				let current = self.current;
+				*self = Self::new(0.0, max).with_rate(self.rate);
+				self.set_current(current)
+			}
+
+			const fn bounds(&self) -> FloatBounds<$float> { FloatBounds::new(0.0, self.max) }
+		}
+
+		#[cfg(feature = "serde")]
+		impl From<ResourceRepr<$float>> for $name {
+			fn from(repr: ResourceRepr<$float>) -> Self { Self::new(repr.current, repr.max).with_rate(repr.rate) }
+		}
+	};
+}
+
+impl_resource!(ResourceF32, f32, "ResourceRepr<f32>");
+impl_resource!(ResourceF64, f64, "ResourceRepr<f64>");
+
+#[cfg(test)]
+mod tests {
+	use float_cmp::approx_eq;
+
+	use super::*;
+
+	#[test]
+	fn constructs_within_bounds() {
+		assert_eq!(ResourceF64::new(150.0, 100.0).current(), 100.0);
+		assert_eq!(ResourceF64::new(-5.0, 100.0).current(), 0.0);
+		assert_eq!(ResourceF32::new(f32::NAN, 100.0).current(), 0.0);
+		assert_eq!(ResourceF64::new(5.0, -100.0).max(), 0.0);
+		assert_eq!(ResourceF64::full(80.0).current(), 80.0);
+		assert_eq!(ResourceF64::empty(80.0).current(), 0.0);
+
+		const MANA: ResourceF32 = ResourceF32::full(50.0).with_rate(2.0);
+		assert_eq!(MANA.rate(), 2.0);
+	}
+
+	#[test]
+	fn reports_overflow_and_underflow() {
+		let mut health = ResourceF64::new(90.0, 100.0);
+		assert_eq!(health.add(5.0), None);
+		assert_eq!(health.add(10.0), Some(ResourceEvent::Overflow { excess: 5.0 }));
+		assert!(health.is_full());
+		assert_eq!(health.add(-120.0), Some(ResourceEvent::Underflow { deficit: 20.0 }));
+		assert!(health.is_empty());
+		assert_eq!(health.set_current(f64::NAN), None);
+		assert_eq!(health.current(), 0.0);
+	}
+
+	#[test]
+	fn modifies_by_ratio() {
+		let mut stamina = ResourceF64::new(50.0, 200.0);
+		assert_eq!(stamina.ratio(), 0.25);
+		assert_eq!(stamina.add_ratio(0.1), None);
+		assert_eq!(stamina.current(), 70.0);
+		assert_eq!(stamina.add_ratio(-0.5), Some(ResourceEvent::Underflow { deficit: 30.0 }));
+		assert_eq!(ResourceF32::full(0.0).ratio(), 0.0);
+	}
+
+	#[test]
+	fn spends_only_when_enough() {
+		let mut mana = ResourceF64::new(30.0, 100.0);
+		assert!(mana.try_spend(20.0));
+		assert!(!mana.try_spend(20.0));
+		assert_eq!(mana.current(), 10.0);
+		assert!(mana.try_spend(10.0));
+		assert!(mana.is_empty());
+
+		let mut stamina = ResourceF64::new(30.0, 100.0);
+		assert!(!stamina.try_spend(-100.0));
+		assert!(!stamina.try_spend(f64::NAN));
+		assert_eq!(stamina.current(), 30.0);
+	}
+
+	#[test]
+	fn ticks_by_rate() {
+		let mut mana = ResourceF64::new(90.0, 100.0).with_rate(4.0);
+		assert_eq!(mana.tick(0.5), None);
+		assert_eq!(mana.current(), 92.0);
+		assert_eq!(mana.tick(3.0), Some(ResourceEvent::Overflow { excess: 4.0 }));
+		assert!(mana.is_full());
+
+		let mut shield = ResourceF32::full(10.0).with_rate(-3.0);
+		assert_eq!(shield.tick(2.0), None);
+		assert!(approx_eq!(f32, shield.current(), 4.0));
+		assert_eq!(shield.tick(2.0), Some(ResourceEvent::Underflow { deficit: 2.0 }));
+		assert!(shield.is_empty());
+	}
+
+	#[test]
+	fn preserves_ratio_when_max_changes() {
+		let mut health = ResourceF64::new(30.0, 120.0).with_rate(1.0);
+		health.set_max(200.0);
+		assert_eq!(health.current(), 50.0);
+		assert_eq!(health.ratio(), 0.25);
+		assert_eq!(health.rate(), 1.0);
+
+		health.set_max(0.0);
+		health.set_max(100.0);
+		assert_eq!(health.current(), 0.0);
+	}
+
+	#[test]
+	fn keeps_current_when_max_changes() {
+		let mut health = ResourceF64::new(80.0, 100.0);
+		assert_eq!(health.set_max_keep_current(150.0), None);
+		assert_eq!(health.current(), 80.0);
+		assert_eq!(health.set_max_keep_current(50.0), Some(ResourceEvent::Overflow { excess: 30.0 }));
+		assert_eq!(health.current(), 50.0);
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn deserializes_within_bounds() {
+		let health = ResourceF64::new(40.0, 100.0).with_rate(-1.5);
+		let text = ron::to_string(&health).unwrap();
+		assert_eq!(ron::from_str::<ResourceF64>(&text).unwrap(), health);
+
+		let clamped: ResourceF32 = ron::from_str("(current: 500.0, max: -3.0, rate: 0.0)").unwrap();
+		assert_eq!((clamped.current(), clamped.max()), (0.0, 0.0));
+		let clamped: ResourceF32 = ron::from_str("(current: 500.0, max: 80.0, rate: 2.0)").unwrap();
+		assert_eq!((clamped.current(), clamped.rate()), (80.0, 2.0));
+	}
+}
@@ -0,0 +1,64 @@
+/// Implements `Serialize` and `Deserialize` for a type generated through [`bound_f32_impl!`] or [`bound_f64_impl!`],
+/// as its bare number, deserializing through the type's `deserialize` option.
+///
+/// Opt-in, so that structs which already derive serde keep compiling. [`bound_f32!`], [`bound_f64!`],
+/// [`BoundedF32`](crate::prelude::BoundedF32) and [`BoundedF64`](crate::prelude::BoundedF64) opt in on their own.
+///
+/// Expands to nothing when the `serde` feature is disabled.
+///
+/// ```
+/// use houtamelo_utils::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub struct Volume {
+///     inner_value: f32,
+/// }
+///
+/// bound_f32_impl!(Volume, 0.0, 1.0);
+/// bound_float_serde!(Volume);
+/// ```
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! bound_float_serde {
+	(impl<$(const $param: ident: $param_ty: ty),* $(,)?> $struct_name: ty) => {
+		$crate::bound_float_serde!(@impl [$(const $param: $param_ty),*] $struct_name);
+	};
+	($struct_name: ty) => {
+		$crate::bound_float_serde!(@impl [] $struct_name);
+	};
+	(@impl [$($generics: tt)*] $struct_name: ty) => {
+		impl<$($generics)*> $crate::serde::Serialize for $struct_name {
+			fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+			where S: $crate::serde::Serializer {
+				return $crate::serde::Serialize::serialize(&self.inner_value, serializer);
+			}
+		}
+
+		impl<'de, $($generics)*> $crate::serde::Deserialize<'de> for $struct_name {
+			fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+			where D: $crate::serde::Deserializer<'de> {
+				use $crate::prelude::{BoundedFloat, NanPolicy, OverflowPolicy};
+				use $crate::serde::de::Error;
+
+				let value = <<Self as BoundedFloat>::Float as $crate::serde::Deserialize>::deserialize(deserializer)?;
+				let strict_nan = matches!(<Self as BoundedFloat>::NAN, NanPolicy::Reject | NanPolicy::DebugPanic);
+
+				return match <Self as BoundedFloat>::DESERIALIZE {
+					OverflowPolicy::Reject => Self::try_new(value).map_err(D::Error::custom),
+					_ if value.is_nan() && strict_nan => Err(D::Error::custom($crate::prelude::BoundError::NaN)),
+					policy => {
+						let bounds = $crate::prelude::FloatBounds::new(Self::MIN, Self::MAX);
+						let value = bounds.replace_nan(value, <Self as BoundedFloat>::NAN, <Self as BoundedFloat>::DEFAULT);
+						Ok(Self { inner_value: bounds.apply(value, policy) })
+					}
+				};
+			}
+		}
+	};
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! bound_float_serde {
+	($($tokens: tt)*) => {};
+}
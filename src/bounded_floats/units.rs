@@ -0,0 +1,143 @@
+crate::bound_f64! {
+	/// A fraction of a whole, `0.0..=1.0`, displayed as a percentage.
+	pub struct Ratio(0.0..=1.0, suffix = "%", scale = 100.0, decimals = 2);
+	/// A fraction of a whole, `0.0..=100.0`.
+	pub struct Percent(0.0..=100.0, suffix = "%", decimals = 2);
+	/// The chance of an event happening, `0.0..=1.0`, displayed as a percentage.
+	pub struct Probability(0.0..=1.0, suffix = "%", scale = 100.0, decimals = 2);
+}
+
+impl Ratio {
+	/// `1.0 - self`.
+	pub fn complement(self) -> Self { Self::new(1.0 - self.get()) }
+}
+
+impl Percent {
+	/// `100.0 - self`.
+	pub fn complement(self) -> Self { Self::new(100.0 - self.get()) }
+}
+
+impl Probability {
+	/// The chance of the event not happening, `1.0 - self`.
+	pub fn complement(self) -> Self { Self::new(1.0 - self.get()) }
+
+	/// The chance of both independent events happening.
+	pub fn and(self, other: Self) -> Self { Self::new(self.get() * other.get()) }
+
+	/// The chance of at least one of the independent events happening.
+	pub fn or(self, other: Self) -> Self { Self::new(self.get() + other.get() - self.get() * other.get()) }
+}
+
+impl std::ops::Not for Probability {
+	type Output = Self;
+
+	fn not(self) -> Self::Output { self.complement() }
+}
+
+impl std::ops::BitAnd for Probability {
+	type Output = Self;
+
+	fn bitand(self, other: Self) -> Self::Output { self.and(other) }
+}
+
+impl std::ops::BitOr for Probability {
+	type Output = Self;
+
+	fn bitor(self, other: Self) -> Self::Output { self.or(other) }
+}
+
+impl From<Percent> for Ratio {
+	fn from(percent: Percent) -> Self { Self::new(percent.get() / 100.0) }
+}
+
+impl From<Probability> for Ratio {
+	fn from(probability: Probability) -> Self { Self::new(probability.get()) }
+}
+
+impl From<Ratio> for Percent {
+	fn from(ratio: Ratio) -> Self { Self::new(ratio.get() * 100.0) }
+}
+
+impl From<Probability> for Percent {
+	fn from(probability: Probability) -> Self { Self::new(probability.get() * 100.0) }
+}
+
+impl From<Ratio> for Probability {
+	fn from(ratio: Ratio) -> Self { Self::new(ratio.get()) }
+}
+
+impl From<Percent> for Probability {
+	fn from(percent: Percent) -> Self { Self::new(percent.get() / 100.0) }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::prelude::{BoundError, ParseBoundedError};
+
+	#[test]
+	fn clamps_into_range() {
+		assert_eq!(Ratio::new(1.5), 1.0);
+		assert_eq!(Percent::new(-5.0), 0.0);
+		assert_eq!(Percent::new(150.0), 100.0);
+		assert_eq!(Probability::new(f64::NAN), 0.0);
+	}
+
+	#[test]
+	fn converts_between_units() {
+		assert_eq!(Percent::from(Ratio::new(0.25)), 25.0);
+		assert_eq!(Ratio::from(Percent::new(25.0)), 0.25);
+		assert_eq!(Probability::from(Percent::new(12.5)), 0.125);
+		assert_eq!(Percent::from(Probability::new(0.125)), 12.5);
+		assert_eq!(Ratio::from(Probability::new(0.3)), 0.3);
+		assert_eq!(Probability::from(Ratio::new(0.3)), 0.3);
+
+		let ratio = Ratio::new(0.1);
+		assert_eq!(Ratio::from(Percent::from(ratio)), ratio);
+	}
+
+	#[test]
+	fn complement() {
+		assert_eq!(Ratio::new(0.25).complement(), 0.75);
+		assert_eq!(Percent::new(30.0).complement(), 70.0);
+		assert_eq!(Probability::new(0.1).complement(), 0.9);
+		assert_eq!(!Probability::new(0.1), 0.9);
+	}
+
+	#[test]
+	fn combines_independent_events() {
+		let heads = Probability::new(0.5);
+		let six = Probability::new(1.0 / 6.0);
+		assert_eq!(heads.and(heads), 0.25);
+		assert_eq!(heads & six, 1.0 / 12.0);
+		assert_eq!(heads.or(heads), 0.75);
+		assert_eq!(heads | Probability::new(1.0), 1.0);
+		assert_eq!(heads | Probability::new(0.0), 0.5);
+		assert_eq!(!(heads & heads), 0.75);
+	}
+
+	#[test]
+	fn displays_as_percentage() {
+		assert_eq!(Ratio::new(0.25).to_string(), "25%");
+		assert_eq!(Ratio::new(0.1).to_string(), "10%");
+		assert_eq!(Percent::new(12.345).to_string(), "12.35%");
+		assert_eq!(Probability::new(1.0 / 3.0).to_string(), "33.33%");
+		assert_eq!(format!("{:.1}", Probability::new(0.5)), "50.0%");
+		assert_eq!(format!("{:6}", Ratio::new(0.5)), "   50%");
+		assert_eq!(format!("{:<8.1}|", Percent::new(5.0)), "5.0%    |");
+	}
+	#[test]
+	fn parses_percentage() {
+		assert_eq!("25%".parse::<Ratio>(), Ok(Ratio::new(0.25)));
+		assert_eq!(" 12.5 % ".parse::<Percent>(), Ok(Percent::new(12.5)));
+		assert_eq!("50".parse::<Probability>(), Ok(Probability::new(0.5)));
+		assert_eq!(
+			"150%".parse::<Ratio>(),
+			Err(ParseBoundedError::OutOfBounds(BoundError::AboveMax { value: 1.5, max: 1.0 }))
+		);
+		assert!(matches!("half".parse::<Percent>(), Err(ParseBoundedError::Invalid(_))));
+
+		let ratio = Ratio::new(0.1);
+		assert_eq!(ratio.to_string().parse::<Ratio>(), Ok(ratio));
+	}
+}
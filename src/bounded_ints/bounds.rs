@@ -0,0 +1,126 @@
+/// The inclusive range `min..=max` of a bounded integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntBounds<I> {
+	pub min: I,
+	pub max: I,
+}
+
+impl<I> IntBounds<I> {
+	pub const fn new(min: I, max: I) -> Self { IntBounds { min, max } }
+}
+
+macro_rules! impl_int_bounds {
+	($($int: ident => $unsigned: ident),* $(,)?) => {
+		$(
+			impl IntBounds<$int> {
+				pub fn contains(self, value: $int) -> bool { self.min <= value && value <= self.max }
+
+				pub fn clamp(self, value: $int) -> $int { Ord::clamp(value, self.min, self.max) }
+
+				/// Returns `value` if it is in range.
+				pub fn check(self, value: $int) -> Option<$int> { self.contains(value).then_some(value) }
+
+				/// Wraps `value` around the range, `max + 1` wraps to `min`.
+				pub fn wrap(self, value: $int) -> $int {
+					let Some(size) = self.size()
+					else { return value; };
+
+					let offset = if value >= self.min {
+						value.abs_diff(self.min) % size
+					} else {
+						(size - self.min.abs_diff(value) % size) % size
+					};
+
+					self.at_offset(offset)
+				}
+
+				/// `value + rhs`, wrapping around the range instead of overflowing.
+				#[allow(unused_comparisons)]
+				pub fn wrapping_add(self, value: $int, rhs: $int) -> $int {
+					let Some(size) = self.size()
+					else { return value.wrapping_add(rhs); };
+
+					let step = rhs.abs_diff(0) % size;
+					self.step(self.wrap(value), step, rhs >= 0, size)
+				}
+
+				/// `value - rhs`, wrapping around the range instead of overflowing.
+				#[allow(unused_comparisons)]
+				pub fn wrapping_sub(self, value: $int, rhs: $int) -> $int {
+					let Some(size) = self.size()
+					else { return value.wrapping_sub(rhs); };
+
+					let step = rhs.abs_diff(0) % size;
+					self.step(self.wrap(value), step, rhs < 0, size)
+				}
+
+				/// The number of values in the range, `None` if it spans the whole type.
+				fn size(self) -> Option<$unsigned> { self.max.abs_diff(self.min).checked_add(1) }
+
+				fn at_offset(self, offset: $unsigned) -> $int { self.min.wrapping_add(offset as $int) }
+
+				/// Moves the in-range `value` by `step < size` values, wrapping around the range.
+				fn step(self, value: $int, step: $unsigned, forward: bool, size: $unsigned) -> $int {
+					let offset = value.abs_diff(self.min);
+					let offset = if forward {
+						if offset >= size - step { offset - (size - step) } else { offset + step }
+					} else if offset >= step {
+						offset - step
+					} else {
+						offset + (size - step)
+					};
+
+					self.at_offset(offset)
+				}
+			}
+		)*
+	};
+}
+
+impl_int_bounds!(
+	i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+	u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const LEVELS: IntBounds<u8> = IntBounds::new(1, 99);
+	const OFFSETS: IntBounds<i8> = IntBounds::new(-3, 3);
+
+	#[test]
+	fn clamp_and_check() {
+		assert_eq!(LEVELS.clamp(0), 1);
+		assert_eq!(LEVELS.clamp(200), 99);
+		assert_eq!(LEVELS.check(100), None);
+		assert_eq!(LEVELS.check(50), Some(50));
+		assert!(OFFSETS.contains(-3));
+		assert!(!OFFSETS.contains(4));
+	}
+
+	#[test]
+	fn wrap_around_range() {
+		assert_eq!(LEVELS.wrap(100), 1);
+		assert_eq!(LEVELS.wrap(0), 99);
+		assert_eq!(LEVELS.wrap(255), 57);
+		assert_eq!(OFFSETS.wrap(4), -3);
+		assert_eq!(OFFSETS.wrap(-4), 3);
+		assert_eq!(OFFSETS.wrap(-128), -2);
+		assert_eq!(OFFSETS.wrap(127), 1);
+		assert_eq!(IntBounds::new(i8::MIN, i8::MAX).wrap(-5), -5);
+		assert_eq!(IntBounds::new(5u32, 5).wrap(100), 5);
+	}
+
+	#[test]
+	fn wrapping_arithmetic() {
+		assert_eq!(LEVELS.wrapping_add(98, 3), 2);
+		assert_eq!(LEVELS.wrapping_add(1, 255), 58);
+		assert_eq!(LEVELS.wrapping_sub(2, 3), 98);
+		assert_eq!(OFFSETS.wrapping_add(3, -7), 3);
+		assert_eq!(OFFSETS.wrapping_add(-3, i8::MIN), 2);
+		assert_eq!(OFFSETS.wrapping_sub(-3, i8::MIN), -1);
+		assert_eq!(IntBounds::new(i8::MIN, i8::MAX).wrapping_add(127, 1), -128);
+		assert_eq!(IntBounds::new(0u128, u128::MAX - 1).wrapping_add(u128::MAX - 1, 2), 1);
+	}
+}
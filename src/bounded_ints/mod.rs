@@ -0,0 +1,470 @@
+pub use self::bounds::*;
+
+mod bounds;
+
+/// Implemented by every type generated through [`bound_int_impl!`].
+pub trait BoundedInt {
+	type Int: Copy;
+
+	const MIN: Self::Int;
+	const MAX: Self::Int;
+
+	fn new(value: Self::Int) -> Self;
+	fn get(&self) -> Self::Int;
+}
+
+/// Implements clamped construction, arithmetic, comparisons and conversions for a struct with a
+/// single private field `inner_value` of the given primitive integer type.
+///
+/// Arithmetic between two bounded values saturates, then clamps into range.
+/// With a primitive operand, the result is the raw primitive, saturated at its own limits.
+/// Dividing by zero saturates towards the sign of the dividend, `0 / 0` being `0`,
+/// and the remainder by zero is the dividend itself.
+///
+/// Fails to compile, once the type is used, if `MIN > MAX`.
+///
+/// Comparisons with the primitive are only implemented with the bounded value on the left:
+/// `impl PartialEq<Level> for u8` would make `Vec<u8> == vec![]` ambiguous.
+/// `checked_*` and `wrapping_*` methods fail or wrap around `MIN..=MAX` instead.
+#[macro_export]
+macro_rules! bound_int_impl {
+	($struct_name: ty: $int: ident, $min: expr, $max: expr $(,)?) => {
+		impl $crate::prelude::BoundedInt for $struct_name {
+			type Int = $int;
+
+			const MIN: $int = <$struct_name>::MIN;
+			const MAX: $int = <$struct_name>::MAX;
+
+			fn new(value: $int) -> Self {
+				return Self::new(value);
+			}
+
+			fn get(&self) -> $int {
+				return self.inner_value;
+			}
+		}
+
+		#[allow(dead_code)]
+		impl $struct_name {
+			pub const MIN: $int = {
+				let (min, max): ($int, $int) = ($min, $max);
+				assert!(min <= max, "bounded integer requires `MIN <= MAX`");
+				min
+			};
+			pub const MAX: $int = {
+				let _ = Self::MIN;
+				$max
+			};
+
+			const BOUNDS: $crate::prelude::IntBounds<$int> = $crate::prelude::IntBounds::new(Self::MIN, Self::MAX);
+
+			/// Clamps `value` into range.
+			pub fn new(value: $int) -> Self {
+				return Self { inner_value: Self::BOUNDS.clamp(value) };
+			}
+
+			/// `None` if `value` is out of range.
+			pub fn checked_new(value: $int) -> Option<Self> {
+				return Self::BOUNDS.check(value).map(|inner_value| Self { inner_value });
+			}
+
+			/// Wraps `value` around the range, `MAX + 1` wraps to `MIN`.
+			pub fn wrapping_new(value: $int) -> Self {
+				return Self { inner_value: Self::BOUNDS.wrap(value) };
+			}
+
+			pub fn get(&self) -> $int {
+				return self.inner_value;
+			}
+
+			/// Clamps `value` into range.
+			pub fn set(&mut self, value: $int) {
+				*self = Self::new(value);
+			}
+
+			/// `None` if the result overflows or is out of range.
+			pub fn checked_add(self, rhs: $int) -> Option<Self> {
+				return self.inner_value.checked_add(rhs).and_then(Self::checked_new);
+			}
+
+			/// `None` if the result overflows or is out of range.
+			pub fn checked_sub(self, rhs: $int) -> Option<Self> {
+				return self.inner_value.checked_sub(rhs).and_then(Self::checked_new);
+			}
+
+			/// `None` if the result overflows or is out of range.
+			pub fn checked_mul(self, rhs: $int) -> Option<Self> {
+				return self.inner_value.checked_mul(rhs).and_then(Self::checked_new);
+			}
+
+			/// `None` if `rhs` is zero, the result overflows or is out of range.
+			pub fn checked_div(self, rhs: $int) -> Option<Self> {
+				return self.inner_value.checked_div(rhs).and_then(Self::checked_new);
+			}
+
+			/// Adds `rhs`, wrapping around the range.
+			pub fn wrapping_add(self, rhs: $int) -> Self {
+				return Self { inner_value: Self::BOUNDS.wrapping_add(self.inner_value, rhs) };
+			}
+
+			/// Subtracts `rhs`, wrapping around the range.
+			pub fn wrapping_sub(self, rhs: $int) -> Self {
+				return Self { inner_value: Self::BOUNDS.wrapping_sub(self.inner_value, rhs) };
+			}
+
+			/// Every value of the range, from `MIN` to `MAX`.
+			pub fn all() -> impl DoubleEndedIterator<Item = Self> {
+				return (Self::MIN..=Self::MAX).map(|inner_value| Self { inner_value });
+			}
+		}
+
+		$crate::__bound_int_operator!($int; $struct_name; Add, add, AddAssign, add_assign, saturating_add);
+		$crate::__bound_int_operator!($int; $struct_name; Sub, sub, SubAssign, sub_assign, saturating_sub);
+		$crate::__bound_int_operator!($int; $struct_name; Mul, mul, MulAssign, mul_assign, saturating_mul);
+		$crate::__bound_int_operator!($int; $struct_name; Div, div, DivAssign, div_assign, saturating_div);
+		$crate::__bound_int_operator!(
+			/// The remainder by zero is the dividend, as with the bounded fixed-point numbers.
+			$int; $struct_name; Rem, rem, RemAssign, rem_assign, saturating_rem
+		);
+
+		impl core::cmp::PartialEq for $struct_name {
+			fn eq(&self, other: &Self) -> bool {
+				return self.inner_value == other.inner_value;
+			}
+		}
+
+		impl core::cmp::Eq for $struct_name {}
+
+		impl core::hash::Hash for $struct_name {
+			fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+				core::hash::Hash::hash(&self.inner_value, state);
+			}
+		}
+
+		impl core::cmp::PartialEq<$int> for $struct_name {
+			fn eq(&self, other: &$int) -> bool {
+				return self.inner_value == *other;
+			}
+		}
+
+		impl core::cmp::PartialOrd for $struct_name {
+			fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+				return Some(core::cmp::Ord::cmp(self, other));
+			}
+		}
+
+		impl core::cmp::Ord for $struct_name {
+			fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+				return self.inner_value.cmp(&other.inner_value);
+			}
+		}
+
+		impl core::cmp::PartialOrd<$int> for $struct_name {
+			fn partial_cmp(&self, other: &$int) -> Option<std::cmp::Ordering> {
+				return self.inner_value.partial_cmp(other);
+			}
+		}
+
+		impl core::convert::From<$int> for $struct_name {
+			fn from(value: $int) -> Self {
+				return Self::new(value);
+			}
+		}
+
+		impl core::default::Default for $struct_name {
+			fn default() -> Self {
+				return Self::new(Self::MIN);
+			}
+		}
+
+		impl std::ops::Deref for $struct_name {
+			type Target = $int;
+
+			fn deref(&self) -> &Self::Target {
+				return &self.inner_value;
+			}
+		}
+	};
+}
+
+/// Declares one or more bounded integer newtypes and implements [`bound_int_impl!`] for each of them.
+///
+/// Attributes (including doc comments) and visibility are forwarded to the generated struct,
+/// which always derives `Debug`, `Clone` and `Copy`.
+///
+/// ```
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_int! {
+///     /// Character level.
+///     pub struct Level(u8, 1..=99);
+///     pub struct Slot(usize, 0..=7);
+/// }
+///
+/// assert_eq!(Level::new(120), 99);
+/// assert_eq!(Level::default(), 1);
+/// assert_eq!(Slot::new(7).wrapping_add(1), 0);
+/// assert_eq!(Slot::all().count(), 8);
+/// ```
+///
+/// Inverted bounds fail to compile once the type is used:
+///
+/// ```compile_fail
+/// use houtamelo_utils::prelude::*;
+///
+/// bound_int! {
+///     struct Inverted(u8, 9..=1);
+/// }
+///
+/// let inverted = Inverted::new(5);
+/// ```
+#[macro_export]
+macro_rules! bound_int {
+	($(
+		$(#[$meta: meta])*
+		$vis: vis struct $struct_name: ident($int: ident, $min: literal ..= $max: literal $(,)?);
+	)*) => {
+		$(
+			$(#[$meta])*
+			#[derive(Debug, Clone, Copy)]
+			$vis struct $struct_name {
+				inner_value: $int,
+			}
+
+			$crate::bound_int_impl!($struct_name: $int, $min, $max);
+		)*
+	};
+}
+
+/// Implements one arithmetic operator for a bounded `$int`, along with its assignment form and the
+/// reference-operand variants of both.
+///
+/// Between two bounded values the result is computed with `$saturating`, then clamped into range.
+/// With a primitive `$int` operand the result is the raw `$int`, also computed with `$saturating`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_int_operator {
+	(
+		$(#[$attr: meta])*
+		$int: ident; $struct_name: ty;
+		$trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident, $saturating: ident
+	) => {
+		$(#[$attr])*
+		impl std::ops::$trait for $struct_name {
+			type Output = Self;
+
+			fn $method(self, other: Self) -> Self::Output {
+				return Self::new($crate::__bound_int_saturating!($int, $saturating, self.inner_value, other.inner_value));
+			}
+		}
+
+		impl std::ops::$trait<$int> for $struct_name {
+			type Output = $int;
+
+			fn $method(self, other: $int) -> Self::Output {
+				return $crate::__bound_int_saturating!($int, $saturating, self.inner_value, other);
+			}
+		}
+
+		impl std::ops::$trait<$struct_name> for $int {
+			type Output = $int;
+
+			fn $method(self, other: $struct_name) -> Self::Output {
+				return $crate::__bound_int_saturating!($int, $saturating, self, other.inner_value);
+			}
+		}
+
+		impl std::ops::$assign_trait for $struct_name {
+			fn $assign_method(&mut self, other: Self) {
+				self.set($crate::__bound_int_saturating!($int, $saturating, self.inner_value, other.inner_value));
+			}
+		}
+
+		impl std::ops::$assign_trait<$int> for $struct_name {
+			fn $assign_method(&mut self, other: $int) {
+				self.set($crate::__bound_int_saturating!($int, $saturating, self.inner_value, other));
+			}
+		}
+
+		$crate::__forward_ref_operator!([] $struct_name, $struct_name; $trait, $method);
+		$crate::__forward_ref_operator!([] $struct_name, $int; $trait, $method);
+		$crate::__forward_ref_operator!([] $int, $struct_name; $trait, $method);
+		$crate::__forward_ref_operator!(@assign [] $struct_name, $struct_name; $assign_trait, $assign_method);
+		$crate::__forward_ref_operator!(@assign [] $struct_name, $int; $assign_trait, $assign_method);
+	};
+}
+
+/// Computes `$lhs` `$saturating` `$rhs` on the primitive `$int`, without panicking.
+///
+/// Division by zero saturates towards the sign of `$lhs`, `0 / 0` being `0`.
+/// The remainder by zero is `$lhs`, and `$int::MIN % -1` is `0`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bound_int_saturating {
+	($int: ident, saturating_div, $lhs: expr, $rhs: expr) => {{
+		let (lhs, rhs): ($int, $int) = ($lhs, $rhs);
+		if rhs != 0 {
+			lhs.saturating_div(rhs)
+		} else if lhs > 0 {
+			$int::MAX
+		} else if lhs == 0 {
+			0
+		} else {
+			$int::MIN
+		}
+	}};
+	($int: ident, saturating_rem, $lhs: expr, $rhs: expr) => {{
+		let (lhs, rhs): ($int, $int) = ($lhs, $rhs);
+		if rhs != 0 { lhs.wrapping_rem(rhs) } else { lhs }
+	}};
+	($int: ident, $saturating: ident, $lhs: expr, $rhs: expr) => {
+		<$int>::$saturating($lhs, $rhs)
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::BTreeSet;
+
+	use crate::prelude::BoundedInt;
+
+	bound_int! {
+		/// Declared through the struct-generating macro.
+		pub struct Level(u8, 1..=99);
+		struct Slot(usize, 0..=7);
+		struct Offset(i8, -3..=3);
+		struct Temperature(i16, -50..=50);
+		struct Gold(u32, 0..=1_000_000);
+		struct Delta(i32, -100..=100);
+		struct Tick(u64, 0..=1_000);
+		struct Balance(i64, -1_000..=1_000);
+		struct Big(u128, 10..=20);
+		struct BigSigned(i128, -20..=-10);
+		struct Full(u16, 0..=65_535);
+		struct Index(isize, -1..=1);
+	}
+
+	#[test]
+	fn new_and_set_clamp() {
+		assert_eq!(Level::new(0), 1);
+		assert_eq!(Level::new(200), Level::MAX);
+		assert_eq!(Offset::new(-100), -3);
+		assert_eq!(Big::new(0), 10);
+		assert_eq!(BigSigned::new(i128::MAX), -10);
+		assert_eq!(Index::new(isize::MIN), -1);
+
+		let mut temperature = Temperature::new(20);
+		temperature.set(75);
+		assert_eq!(temperature.get(), 50);
+		assert_eq!(*temperature, 50);
+		assert_eq!(Gold::from(2_000_000), 1_000_000);
+	}
+
+	#[test]
+	fn default_is_min() {
+		assert_eq!(Level::default(), 1);
+		assert_eq!(Delta::default(), -100);
+		assert_eq!(Full::default(), 0);
+		assert_eq!(<Balance as BoundedInt>::MIN, -1_000);
+	}
+
+	#[test]
+	#[allow(clippy::op_ref)]
+	fn operators_saturate_then_clamp() {
+		assert_eq!(Level::new(90) + Level::new(20), 99);
+		assert_eq!(Level::new(5) - Level::new(10), 1);
+		assert_eq!(Tick::new(600) * Tick::new(2), 1_000);
+		assert_eq!(Delta::new(-100) / Delta::new(-1), 100);
+		assert_eq!(Slot::new(7) % Slot::new(4), 3);
+		assert_eq!(Full::new(65_535) + Full::new(1), 65_535);
+
+		assert_eq!(Level::new(90) + 20u8, 110);
+		assert_eq!(10i8 - Offset::new(3), 7);
+		assert_eq!(&Slot::new(2) * &Slot::new(3), 6);
+
+		let mut balance = Balance::new(900);
+		balance += 500;
+		assert_eq!(balance, 1_000);
+		balance -= Balance::new(1_000);
+		balance /= &2;
+		balance *= i64::MAX;
+		assert_eq!(balance, 0);
+		balance -= 1;
+		balance %= 1;
+		assert_eq!(balance, 0);
+	}
+
+	#[test]
+	fn operators_never_panic() {
+		assert_eq!(Slot::new(3) / Slot::new(0), Slot::MAX);
+		assert_eq!(Slot::new(0) / Slot::new(0), 0);
+		assert_eq!(Slot::new(3) % Slot::new(0), 3);
+		assert_eq!(Offset::new(-2) / Offset::new(0), Offset::MIN);
+		assert_eq!(i8::MIN % Offset::new(-1), 0);
+
+		assert_eq!(Level::new(99) + 200u8, u8::MAX);
+		assert_eq!(Level::new(1) - 2u8, 0);
+		assert_eq!(Level::new(50) * 10u8, u8::MAX);
+		assert_eq!(5u8 - Level::new(10), 0);
+		assert_eq!(Level::new(10) / 0u8, u8::MAX);
+		assert_eq!(Offset::new(-3) / 0i8, i8::MIN);
+		assert_eq!(Offset::new(-3) % 0i8, -3);
+		assert_eq!(i8::MIN / Offset::new(-1), i8::MAX);
+
+		let mut slot = Slot::new(4);
+		slot /= 0;
+		assert_eq!(slot, Slot::MAX);
+		slot %= 0;
+		assert_eq!(slot, Slot::MAX);
+	}
+
+	#[test]
+	fn comparisons_and_hashing() {
+		assert!(Level::new(5) < Level::new(6));
+		assert!(Level::new(5) > 4);
+		assert_eq!(Level::new(5), 5);
+
+		let set = BTreeSet::from([Slot::new(3), Slot::new(1), Slot::new(3)]);
+		assert_eq!(set.into_iter().collect::<Vec<_>>(), [Slot::new(1), Slot::new(3)]);
+
+		let hashed = std::collections::HashSet::from([Gold::new(5), Gold::new(5)]);
+		assert_eq!(hashed.len(), 1);
+	}
+
+	#[test]
+	fn checked_variants() {
+		assert_eq!(Level::checked_new(0), None);
+		assert_eq!(Level::checked_new(50), Some(Level::new(50)));
+		assert_eq!(Level::new(98).checked_add(1), Some(Level::new(99)));
+		assert_eq!(Level::new(98).checked_add(2), None);
+		assert_eq!(Level::new(98).checked_add(255), None);
+		assert_eq!(Level::new(2).checked_sub(2), None);
+		assert_eq!(Offset::new(2).checked_mul(-1), Some(Offset::new(-2)));
+		assert_eq!(Offset::new(2).checked_mul(2), None);
+		assert_eq!(Offset::new(2).checked_div(0), None);
+		assert_eq!(Delta::new(-100).checked_div(-1), Some(Delta::new(100)));
+	}
+
+	#[test]
+	fn wrapping_variants() {
+		assert_eq!(Level::wrapping_new(100), 1);
+		assert_eq!(Level::wrapping_new(0), 99);
+		assert_eq!(Slot::new(6).wrapping_add(3), 1);
+		assert_eq!(Slot::new(1).wrapping_sub(3), 6);
+		assert_eq!(Offset::new(3).wrapping_add(1), -3);
+		assert_eq!(Offset::new(-3).wrapping_add(-1), 3);
+		assert_eq!(Offset::new(0).wrapping_sub(i8::MIN), 2);
+		assert_eq!(Big::new(20).wrapping_add(u128::MAX), 11);
+		assert_eq!(BigSigned::new(-10).wrapping_add(1), -20);
+		assert_eq!(Full::new(65_535).wrapping_add(1), 0);
+	}
+
+	#[test]
+	fn iterates_over_full_range() {
+		assert_eq!(Offset::all().map(|offset| offset.get()).collect::<Vec<_>>(), [-3, -2, -1, 0, 1, 2, 3]);
+		assert_eq!(Level::all().count(), 99);
+		assert_eq!(Level::all().next_back(), Some(Level::MAX.into()));
+		assert_eq!(Full::all().count(), 65_536);
+		assert_eq!(BigSigned::all().next(), Some(BigSigned::new(-20)));
+	}
+}
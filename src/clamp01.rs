@@ -1,15 +1,180 @@
-pub trait Clamp01<T> {
-	fn clamp01(self) -> Self;
+use std::ops::RangeInclusive;
+
+use crate::prelude::{FloatBounds, NanPolicy};
+
+/// Clamps into `0..=1`, element-wise for arrays, mutable slices and tuples.
+pub trait Clamp01<T: ?Sized> {
+	/// Clamps to `0.0..=1.0`, NaN becomes `0.0`.
+	fn clamp01(self) -> Self
+	where
+		Self: Sized,
+	{
+		self.clamp01_with(NanPolicy::Min)
+	}
+
+	/// Clamps to `0.0..=1.0`, NaN is handled by `nan`, both `Min` and `Default` replace it with `0.0`.
+	///
+	/// Integers ignore `nan`.
+	fn clamp01_with(self, nan: NanPolicy) -> Self;
+}
+
+/// Clamps from below only, NaN becomes `min`.
+pub trait ClampMin<Bound = Self> {
+	fn clamp_min(self, min: Bound) -> Self;
+}
+
+/// Clamps from above only, NaN becomes `max`.
+pub trait ClampMax<Bound = Self> {
+	fn clamp_max(self, max: Bound) -> Self;
+}
+
+/// Clamps into `range`, NaN becomes its start.
+///
+/// # Panics
+/// If the range's start is greater than its end.
+pub trait ClampRange<Bound = Self> {
+	fn clamp_range(self, range: RangeInclusive<Bound>) -> Self;
+}
+
+macro_rules! impl_int_clamps {
+	($($int: ident),* $(,)?) => {
+		$(
+			impl Clamp01<$int> for $int {
+				fn clamp01_with(self, _nan: NanPolicy) -> $int { Ord::clamp(self, 0, 1) }
+			}
+
+			impl ClampMin for $int {
+				fn clamp_min(self, min: $int) -> $int { Ord::max(self, min) }
+			}
+
+			impl ClampMax for $int {
+				fn clamp_max(self, max: $int) -> $int { Ord::min(self, max) }
+			}
+
+			impl ClampRange for $int {
+				fn clamp_range(self, range: RangeInclusive<$int>) -> $int { Ord::clamp(self, *range.start(), *range.end()) }
+			}
+		)*
+	};
+}
+
+impl_int_clamps!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float_clamps {
+	($($float: ident),* $(,)?) => {
+		$(
+			impl Clamp01<$float> for $float {
+				fn clamp01_with(self, nan: NanPolicy) -> $float {
+					let bounds = FloatBounds::new(0.0 as $float, 1.0);
+					bounds.clamp(bounds.replace_nan(self, nan, 0.0))
+				}
+			}
+
+			impl ClampMin for $float {
+				fn clamp_min(self, min: $float) -> $float { $float::max(self, min) }
+			}
+
+			impl ClampMax for $float {
+				fn clamp_max(self, max: $float) -> $float { $float::min(self, max) }
+			}
+
+			impl ClampRange for $float {
+				fn clamp_range(self, range: RangeInclusive<$float>) -> $float {
+					let bounds = FloatBounds::new(*range.start(), *range.end());
+					bounds.clamp(bounds.replace_nan(self, NanPolicy::Min, bounds.min))
+				}
+			}
+		)*
+	};
 }
 
-impl Clamp01<f32> for f32 {
-	fn clamp01(self) -> f32 { f32::clamp(self, 0.0, 1.0) }
+impl_float_clamps!(f32, f64);
+
+impl<T: Clamp01<T>, const N: usize> Clamp01<[T; N]> for [T; N] {
+	fn clamp01_with(self, nan: NanPolicy) -> Self { self.map(|value| value.clamp01_with(nan)) }
+}
+
+impl<T: ClampMin + Copy, const N: usize> ClampMin<T> for [T; N] {
+	fn clamp_min(self, min: T) -> Self { self.map(|value| value.clamp_min(min)) }
 }
 
-impl Clamp01<f64> for f64 {
-	fn clamp01(self) -> f64 { f64::clamp(self, 0.0, 1.0) }
+impl<T: ClampMax + Copy, const N: usize> ClampMax<T> for [T; N] {
+	fn clamp_max(self, max: T) -> Self { self.map(|value| value.clamp_max(max)) }
 }
 
+impl<T: ClampRange + Copy, const N: usize> ClampRange<T> for [T; N] {
+	fn clamp_range(self, range: RangeInclusive<T>) -> Self { self.map(|value| value.clamp_range(range.clone())) }
+}
+
+/// Clamps the slice in place, returning it back.
+impl<T: Clamp01<T> + Copy> Clamp01<[T]> for &mut [T] {
+	fn clamp01_with(self, nan: NanPolicy) -> Self {
+		for value in self.iter_mut() {
+			*value = value.clamp01_with(nan);
+		}
+
+		self
+	}
+}
+
+/// Clamps the slice in place, returning it back.
+impl<T: ClampMin + Copy> ClampMin<T> for &mut [T] {
+	fn clamp_min(self, min: T) -> Self {
+		for value in self.iter_mut() {
+			*value = value.clamp_min(min);
+		}
+
+		self
+	}
+}
+
+/// Clamps the slice in place, returning it back.
+impl<T: ClampMax + Copy> ClampMax<T> for &mut [T] {
+	fn clamp_max(self, max: T) -> Self {
+		for value in self.iter_mut() {
+			*value = value.clamp_max(max);
+		}
+
+		self
+	}
+}
+
+/// Clamps the slice in place, returning it back.
+impl<T: ClampRange + Copy> ClampRange<T> for &mut [T] {
+	fn clamp_range(self, range: RangeInclusive<T>) -> Self {
+		for value in self.iter_mut() {
+			*value = value.clamp_range(range.clone());
+		}
+
+		self
+	}
+}
+
+macro_rules! impl_tuple_clamps {
+	($(($($element: ident: $index: tt),+)),* $(,)?) => {
+		$(
+			impl<$($element: Clamp01<$element>),+> Clamp01<($($element,)+)> for ($($element,)+) {
+				fn clamp01_with(self, nan: NanPolicy) -> Self { ($(self.$index.clamp01_with(nan),)+) }
+			}
+
+			impl<T: ClampMin + Copy> ClampMin<T> for ($(impl_tuple_clamps!(@same $element T),)+) {
+				fn clamp_min(self, min: T) -> Self { ($(self.$index.clamp_min(min),)+) }
+			}
+
+			impl<T: ClampMax + Copy> ClampMax<T> for ($(impl_tuple_clamps!(@same $element T),)+) {
+				fn clamp_max(self, max: T) -> Self { ($(self.$index.clamp_max(max),)+) }
+			}
+
+			impl<T: ClampRange + Copy> ClampRange<T> for ($(impl_tuple_clamps!(@same $element T),)+) {
+				fn clamp_range(self, range: RangeInclusive<T>) -> Self { ($(self.$index.clamp_range(range.clone()),)+) }
+			}
+		)*
+	};
+	(@same $element: ident $ty: ty) => { $ty };
+}
+
+impl_tuple_clamps!((A: 0, B: 1), (A: 0, B: 1, C: 2), (A: 0, B: 1, C: 2, D: 3));
+
 #[cfg(test)]
 mod tests {
 	use float_cmp::approx_eq;
@@ -44,10 +209,46 @@ mod tests {
 	}
 
 	#[test]
-	fn should_return_nan_if_input_value_is_nan() {
+	fn should_return_0_if_input_value_is_nan() {
 		let input = f32::NAN;
 		let result = input.clamp01();
-		assert!(result.is_nan());
+		assert!(approx_eq!(
+			f32, result, 0.0
+		));
+	}
+
+	#[test]
+	fn should_return_0_if_input_value_is_nan_with_min_or_default_policy() {
+		assert!(approx_eq!(
+			f64, f64::NAN.clamp01_with(NanPolicy::Min), 0.0
+		));
+		assert!(approx_eq!(
+			f64, f64::NAN.clamp01_with(NanPolicy::Default), 0.0
+		));
+	}
+
+	#[test]
+	#[should_panic(expected = "NaN")]
+	fn should_panic_if_input_value_is_nan_with_reject_policy() {
+		f32::NAN.clamp01_with(NanPolicy::Reject);
+	}
+
+	#[test]
+	#[cfg_attr(debug_assertions, should_panic(expected = "NaN"))]
+	fn should_panic_only_in_debug_if_input_value_is_nan_with_debug_panic_policy() {
+		assert!(approx_eq!(
+			f32, f32::NAN.clamp01_with(NanPolicy::DebugPanic), 0.0
+		));
+	}
+
+	#[test]
+	fn should_clamp_infinities() {
+		assert!(approx_eq!(
+			f32, f32::INFINITY.clamp01(), 1.0
+		));
+		assert!(approx_eq!(
+			f32, f32::NEG_INFINITY.clamp01(), 0.0
+		));
 	}
 
 	#[test]
@@ -76,4 +277,84 @@ mod tests {
 			f32, result, 0.000001
 		));
 	}
+
+	#[test]
+	fn should_clamp_integers_to_0_and_1() {
+		assert_eq!(5u8.clamp01(), 1);
+		assert_eq!((-5i64).clamp01(), 0);
+		assert_eq!(0usize.clamp01(), 0);
+		assert_eq!(1i128.clamp01_with(NanPolicy::Reject), 1);
+	}
+
+	#[test]
+	fn should_clamp_one_side_only() {
+		assert_eq!(5i32.clamp_min(10), 10);
+		assert_eq!(15i32.clamp_min(10), 15);
+		assert_eq!(15u16.clamp_max(10), 10);
+		assert!(approx_eq!(
+			f32, (-2.0f32).clamp_min(-1.0), -1.0
+		));
+		assert!(approx_eq!(
+			f64, 1e9f64.clamp_max(5.0), 5.0
+		));
+		assert!(approx_eq!(
+			f32, f32::NAN.clamp_min(3.0), 3.0
+		));
+		assert!(approx_eq!(
+			f32, f32::NAN.clamp_max(3.0), 3.0
+		));
+	}
+
+	#[test]
+	fn should_clamp_into_range() {
+		assert_eq!(150u8.clamp_range(1..=99), 99);
+		assert_eq!((-7isize).clamp_range(-3..=3), -3);
+		assert!(approx_eq!(
+			f64, 400.0f64.clamp_range(0.0..=360.0), 360.0
+		));
+		assert!(approx_eq!(
+			f32, f32::NAN.clamp_range(-1.0..=1.0), -1.0
+		));
+	}
+
+	#[test]
+	#[should_panic]
+	fn should_panic_if_range_is_inverted() {
+		let (start, end) = (10, 0);
+		5i32.clamp_range(start..=end);
+	}
+
+	#[test]
+	fn should_clamp_arrays_element_wise() {
+		assert_eq!([1.5f32, -0.5, 0.25, f32::NAN].clamp01(), [1.0, 0.0, 0.25, 0.0]);
+		assert_eq!([0.5f64, 2.0].clamp_min(1.0), [1.0, 2.0]);
+		assert_eq!([300u16, 20].clamp_max(255), [255, 20]);
+		assert_eq!([-10.0f32, 10.0, 300.0].clamp_range(0.0..=255.0), [0.0, 10.0, 255.0]);
+	}
+
+	#[test]
+	fn should_clamp_slices_in_place() {
+		let mut colour = vec![1.2f32, 0.5, -0.1];
+		colour.as_mut_slice().clamp01();
+		assert_eq!(colour, [1.0, 0.5, 0.0]);
+
+		colour[..2].as_mut().clamp_max(0.75);
+		assert_eq!(colour, [0.75, 0.5, 0.0]);
+
+		let clamped = colour.as_mut_slice().clamp_min(0.6);
+		assert_eq!(clamped, [0.75, 0.6, 0.6]);
+
+		let mut values = [5i32, 15, 25];
+		values.as_mut_slice().clamp_range(10..=20);
+		assert_eq!(values, [10, 15, 20]);
+	}
+
+	#[test]
+	fn should_clamp_tuples_element_wise() {
+		assert_eq!((1.5f32, -2.0f64).clamp01(), (1.0, 0.0));
+		assert_eq!((0.5f32, 2.0f32, f32::NAN).clamp01_with(NanPolicy::Default), (0.5, 1.0, 0.0));
+		assert_eq!((1.0f32, 0.2, 0.3, 4.0).clamp_range(0.25..=0.75), (0.75, 0.25, 0.3, 0.75));
+		assert_eq!((1u8, 9u8).clamp_min(5), (5, 9));
+		assert_eq!((1.0f64, 9.0, -1.0).clamp_max(5.0), (1.0, 5.0, -1.0));
+	}
 }
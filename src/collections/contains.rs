@@ -0,0 +1,115 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::prelude::*;
+
+/// A collection that can tell whether it holds an item and list its items,
+/// the other operand of [`SetAlgebra`].
+pub trait Contains<T: ?Sized> {
+    fn contains_item(&self, item: &T) -> bool;
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+}
+
+impl<T: ?Sized, C: Contains<T> + ?Sized> Contains<T> for &C {
+    fn contains_item(&self, item: &T) -> bool { (**self).contains_item(item) }
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        (**self).items()
+    }
+}
+
+impl<T: PartialEq, const N: usize> Contains<T> for SmallSet<T, N> {
+    fn contains_item(&self, item: &T) -> bool { self.iter().any(|value| value == item) }
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
+impl<T: Ord, const N: usize> Contains<T> for SortedSmallSet<T, N> {
+    fn contains_item(&self, item: &T) -> bool { self.contains(item) }
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq> Contains<T> for IndexedSet<T> {
+    fn contains_item(&self, item: &T) -> bool { self.index_of(item).is_some() }
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
+impl<T: Hash + Eq, S: BuildHasher> Contains<T> for HashSet<T, S> {
+    fn contains_item(&self, item: &T) -> bool { self.contains(item) }
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
+impl<T: Ord> Contains<T> for BTreeSet<T> {
+    fn contains_item(&self, item: &T) -> bool { self.contains(item) }
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq> Contains<T> for [T] {
+    fn contains_item(&self, item: &T) -> bool { self.contains(item) }
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq, const N: usize> Contains<T> for [T; N] {
+    fn contains_item(&self, item: &T) -> bool { self.contains(item) }
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq> Contains<T> for Vec<T> {
+    fn contains_item(&self, item: &T) -> bool { self.contains(item) }
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+}
@@ -259,6 +259,46 @@ impl<'a, const COUNT: usize, T> IntoIterator for &'a mut CountOrMore<COUNT, T> {
     fn into_iter(self) -> Self::IntoIter { self.fixed.iter_mut().chain(self.dynamic.iter_mut()) }
 }
 
+#[cfg(feature = "proptest")]
+impl<const COUNT: usize, T: proptest::arbitrary::Arbitrary> proptest::arbitrary::Arbitrary for CountOrMore<COUNT, T> {
+    type Parameters = <Vec<T> as proptest::arbitrary::Arbitrary>::Parameters;
+    type Strategy = impl proptest::strategy::Strategy<Value = Self>;
+
+    /// Always generates the `COUNT` fixed items, `args` only configures the extras.
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        let items = (proptest::arbitrary::any::<[T; COUNT]>(), proptest::arbitrary::any_with::<Vec<T>>(args));
+        proptest::strategy::Strategy::prop_map(items, |(fixed, dynamic)| CountOrMore { fixed, dynamic })
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<const COUNT: usize, T: quickcheck::Arbitrary> quickcheck::Arbitrary for CountOrMore<COUNT, T> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        CountOrMore {
+            fixed:   std::array::from_fn(|_| T::arbitrary(g)),
+            dynamic: Vec::arbitrary(g),
+        }
+    }
+
+    /// Shrinks the extras first, then each fixed item, never dropping below `COUNT` items.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let fixed = self.fixed.clone();
+        let shrunk_dynamic = self.dynamic.shrink().map(move |dynamic| CountOrMore { fixed: fixed.clone(), dynamic });
+
+        let this = self.clone();
+        let shrunk_fixed = (0..COUNT).flat_map(move |index| {
+            let this = this.clone();
+            this.fixed[index].shrink().map(move |item| {
+                let mut shrunk = this.clone();
+                shrunk.fixed[index] = item;
+                shrunk
+            })
+        });
+
+        Box::new(shrunk_dynamic.chain(shrunk_fixed))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -567,4 +607,29 @@ mod tests {
 
         assert!(!count_or_more.try_retain(|x| *x < 6));
     }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_proptest_generates_at_least_count(items: CountOrMore<3, u8>) {
+            proptest::prop_assert!(items.len() >= 3);
+            proptest::prop_assert_eq!(items.iter().count(), items.len());
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn test_quickcheck_never_shrinks_below_count() {
+        use quickcheck::{Arbitrary, Gen};
+
+        let mut g = Gen::new(10);
+        for _ in 0..100 {
+            let items = CountOrMore::<3, u8>::arbitrary(&mut g);
+            assert!(items.len() >= 3);
+            assert!(items.shrink().take(100).all(|shrunk| shrunk.len() >= 3));
+        }
+
+        let minimal = CountOrMore::new([0u8, 0, 0], vec![]);
+        assert_eq!(minimal.shrink().count(), 0);
+    }
 }
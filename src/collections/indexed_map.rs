@@ -1,51 +1,156 @@
 use std::{
 	cmp::{Ordering, Ord},
 	collections::TryReserveError,
-	fmt::Debug,
-	ops::{Index, IndexMut, RangeBounds},
-	vec::Drain,
+	fmt::{self, Debug},
+	ops::{Bound, Index, IndexMut, RangeBounds},
 };
 
 use crate::prelude::*;
 
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct IndexedMap<Key, Val> {
+/// A `Vec` of key-value pairs with unique keys, in insertion order.
+///
+/// Keys are found by comparing them one by one, unless the map is built with a [`HashIndex`],
+/// see [`IndexedMap::hashed`], which finds them in constant time and is kept up to date by every method.
+#[derive(Clone)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(
+		from = "IndexedMapRepr<Key, Val>",
+		bound(
+			serialize = "Key: serde::Serialize, Val: serde::Serialize",
+			deserialize = "Key: serde::Deserialize<'de>, Val: serde::Deserialize<'de>, I: KeyIndex<Key>"
+		)
+	)
+)]
+pub struct IndexedMap<Key, Val, I = LinearIndex> {
 	vec: Vec<(Key, Val)>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	index: I,
 }
 
-impl<Key, Val> Default for IndexedMap<Key, Val> {
-	fn default() -> Self { IndexedMap { vec: Vec::new() } }
+/// The serialized form of [`IndexedMap`], without its index.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "IndexedMap")]
+struct IndexedMapRepr<Key, Val> {
+	vec: Vec<(Key, Val)>,
+}
+
+#[cfg(feature = "serde")]
+impl<Key, Val, I: KeyIndex<Key>> From<IndexedMapRepr<Key, Val>> for IndexedMap<Key, Val, I> {
+	fn from(repr: IndexedMapRepr<Key, Val>) -> Self { Self::from_vec(repr.vec) }
 }
 
-impl<Key, Val> Deref for IndexedMap<Key, Val> {
+impl<Key: Debug, Val: Debug, I> Debug for IndexedMap<Key, Val, I> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("IndexedMap").field("vec", &self.vec).finish()
+	}
+}
+
+impl<Key, Val, I: Default> Default for IndexedMap<Key, Val, I> {
+	fn default() -> Self { IndexedMap { vec: Vec::new(), index: I::default() } }
+}
+
+impl<Key, Val, I> Deref for IndexedMap<Key, Val, I> {
 	type Target = Vec<(Key, Val)>;
 	fn deref(&self) -> &Self::Target { &self.vec }
 }
 
 impl<Key, Val> IndexedMap<Key, Val> {
 	pub fn new() -> Self { Self::default() }
-	pub fn with_capacity(capacity: usize) -> Self { Self { vec: Vec::with_capacity(capacity) } }
+	pub fn with_capacity(capacity: usize) -> Self { Self { vec: Vec::with_capacity(capacity), index: LinearIndex } }
+}
+
+impl<Key: Hash + Eq, Val> IndexedMap<Key, Val, HashIndex> {
+	/// An empty map that finds its keys by hash.
+	pub fn hashed() -> Self { Self::default() }
+	pub fn hashed_with_capacity(capacity: usize) -> Self { Self { vec: Vec::with_capacity(capacity), index: HashIndex::default() } }
+}
+
+impl<Key, Val, I: KeyIndex<Key>> IndexedMap<Key, Val, I> {
 	pub fn reserve(&mut self, additional: usize) { self.vec.reserve(additional) }
 	pub fn reserve_exact(&mut self, additional: usize) { self.vec.reserve_exact(additional) }
 	pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> { self.vec.try_reserve(additional) }
 	pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> { self.vec.try_reserve_exact(additional) }
 	pub fn shrink_to_fit(&mut self) { self.vec.shrink_to_fit() }
 	pub fn shrink_to(&mut self, min_capacity: usize) { self.vec.shrink_to(min_capacity) }
-	pub fn truncate(&mut self, len: usize) { self.vec.truncate(len) }
-	pub fn swap_remove(&mut self, index: usize) -> (Key, Val) { self.vec.swap_remove(index) }
-	pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, (Key, Val)> { self.vec.drain(range) }
-	pub fn clear(&mut self) { self.vec.clear(); }
-	pub fn pop(&mut self) -> Option<(Key, Val)> { self.vec.pop() }
-	
+
+	pub fn truncate(&mut self, len: usize) {
+		for index in len..self.vec.len() {
+			self.index.removed(&self.vec[index].0, index);
+		}
+
+		self.vec.truncate(len)
+	}
+
+	/// # Panics
+	/// If `index` >= `len`
+	pub fn swap_remove(&mut self, index: usize) -> (Key, Val) {
+		let last = self.vec.len() - 1;
+		self.index.removed(&self.vec[index].0, index);
+		if index != last {
+			self.index.removed(&self.vec[last].0, last);
+			self.index.inserted(&self.vec[last].0, index);
+		}
+
+		self.vec.swap_remove(index)
+	}
+
+	/// The entries are removed right away, whether or not the iterator is consumed,
+	/// so that leaking it cannot leave the index pointing past the end of the map.
+	pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> std::vec::IntoIter<(Key, Val)> {
+		let start = match range.start_bound() {
+			Bound::Included(start) => *start,
+			Bound::Excluded(start) => start + 1,
+			Bound::Unbounded => 0,
+		};
+		let end = match range.end_bound() {
+			Bound::Included(end) => end + 1,
+			Bound::Excluded(end) => *end,
+			Bound::Unbounded => self.vec.len(),
+		};
+
+		let drained = self.vec.drain(range).collect::<Vec<_>>();
+		self.index.remapped(|position| {
+			if position < start {
+				Some(position)
+			} else if position >= end {
+				Some(position - drained.len())
+			} else {
+				None
+			}
+		});
+		drained.into_iter()
+	}
+
+	pub fn clear(&mut self) {
+		self.vec.clear();
+		self.index.rebuild(std::iter::empty());
+	}
+
+	pub fn pop(&mut self) -> Option<(Key, Val)> {
+		let (key, value) = self.vec.pop()?;
+		self.index.removed(&key, self.vec.len());
+		Some((key, value))
+	}
+
 	pub fn retain(&mut self, mut f: impl FnMut(&Key, &Val) -> bool) {
-		self.vec.retain(|(k, v)| f(k, v))
+		self.retain_mut(|k, v| f(k, v));
 	}
 
 	pub fn retain_mut(&mut self, mut f: impl FnMut(&Key, &mut Val) -> bool) {
-		self.vec.retain_mut(|(k, v)| f(k, v))
+		let mut new_positions = Vec::with_capacity(self.vec.len());
+		let mut kept = 0;
+		self.vec.retain_mut(|(k, v)| {
+			let keep = f(k, v);
+			new_positions.push(keep.then_some(kept));
+			kept += keep as usize;
+			keep
+		});
+		self.index.remapped(|position| new_positions[position]);
 	}
-	
+
 	pub fn get(&self, index: usize) -> Option<(&Key, &Val)> {
 		self.vec.get(index).map(|(k, v)| (k, v))
 	}
@@ -66,12 +171,26 @@ impl<Key, Val> IndexedMap<Key, Val> {
 		if index >= self.len() {
 			None
 		} else {
-			Some(self.vec.remove(index))
+			let entry = self.vec.remove(index);
+			self.index.remapped(|position| match position.cmp(&index) {
+				Ordering::Less => Some(position),
+				Ordering::Equal => None,
+				Ordering::Greater => Some(position - 1),
+			});
+			Some(entry)
 		}
 	}
 
 	pub fn swap(&mut self, idx_a: usize, idx_b: usize) {
+		if idx_a == idx_b {
+			return;
+		}
+
+		self.index.removed(&self.vec[idx_a].0, idx_a);
+		self.index.removed(&self.vec[idx_b].0, idx_b);
 		self.vec.swap(idx_a, idx_b);
+		self.index.inserted(&self.vec[idx_a].0, idx_a);
+		self.index.inserted(&self.vec[idx_b].0, idx_b);
 	}
 
 	pub fn keys(&self) -> impl ExactSizeIterator<Item = &Key> {
@@ -92,30 +211,51 @@ impl<Key, Val> IndexedMap<Key, Val> {
 	
 	pub fn sort_by(&mut self, mut compare: impl FnMut(&Key, &Key) -> Ordering) {
 		self.vec.sort_by(|(a, _), (b, _)| compare(a, b));
+		self.reindex();
+	}
+
+	/// Moves the entries into a map with a different kind of index.
+	pub fn reindexed<J: KeyIndex<Key>>(self) -> IndexedMap<Key, Val, J> { IndexedMap::from_vec(self.vec) }
+
+	/// Trusts that the keys of `vec` are unique.
+	fn from_vec(vec: Vec<(Key, Val)>) -> Self {
+		let mut map = IndexedMap { vec, index: I::default() };
+		map.reindex();
+		map
+	}
+
+	fn reindex(&mut self) {
+		self.index.rebuild(self.vec.iter().map(|(key, _)| key).enumerate());
 	}
 }
 
-impl<Key: PartialEq, Val> IndexedMap<Key, Val> {
+impl<Key, Val, I: KeyLookup<Key>> IndexedMap<Key, Val, I> {
 	/// Returns the old value if the key already exists.
 	///
+	/// `index` is a position in the map before the old entry is taken out,
+	/// so an existing key moved forward ends up right before the entry that was at `index`.
+	///
 	/// # Panics
 	/// If `index` > `len`
 	pub fn insert(&mut self, key: Key, value: Val, index: usize) -> Option<Val> {
-		let old_value = self.remove(&key);
+		let old_index = self.key_index(&key);
+		let old_value = old_index.and_then(|idx| self.remove_at(idx)).map(|(_, v)| v);
 		let index =
-			if old_value.is_some() {
-				index - 1
-			} else {
-				index
+			match old_index {
+				Some(old_index) if old_index < index => index - 1,
+				_ => index,
 			};
 
 		self.vec.insert(index, (key, value));
+		self.index.remapped(|position| Some(if position >= index { position + 1 } else { position }));
+		self.index.inserted(&self.vec[index].0, index);
 		old_value
 	}
 
 	/// Returns the old value if the key already exists.
 	pub fn push(&mut self, key: Key, value: Val) -> Option<Val> {
 		let old_value = self.remove(&key);
+		self.index.inserted(&key, self.vec.len());
 		self.vec.push((key, value));
 		old_value
 	}
@@ -125,43 +265,44 @@ impl<Key: PartialEq, Val> IndexedMap<Key, Val> {
 	}
 
 	pub fn get_value(&self, key: &Key) -> Option<&Val> {
-		self.vec.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+		self.key_index(key).map(|idx| &self.vec[idx].1)
 	}
 
 	pub fn get_value_mut(&mut self, key: &Key) -> Option<&mut Val> {
-		self.vec.iter_mut().find(|(k, _)| k == key).map(|(_, v)| v)
+		self.key_index(key).map(|idx| &mut self.vec[idx].1)
 	}
 
 	pub fn key_index(&self, key: &Key) -> Option<usize> {
-		self.vec.iter().position(|(k, _)| k == key)
+		self.index.find(&self.vec, key)
 	}
 
 	pub fn remove(&mut self, key: &Key) -> Option<Val> {
-		self.key_index(key).map(|idx| self.vec.remove(idx).1)
+		self.key_index(key).and_then(|idx| self.remove_at(idx)).map(|(_, v)| v)
 	}
 
 	pub fn contains_key(&self, key: &Key) -> bool {
-		self.vec.iter().any(|(k, _)| k == key)
+		self.key_index(key).is_some()
 	}
 
 	pub fn append(&mut self, other: &mut Self) {
-		self.extend(other.vec.drain(..))
+		self.extend(other.drain(..))
 	}
 }
 
-impl<Key: Ord, Val> IndexedMap<Key, Val> { 
+impl<Key: Ord, Val, I: KeyIndex<Key>> IndexedMap<Key, Val, I> { 
 	pub fn sort(&mut self) {
-		self.vec.sort_by(|(a, _), (b, _)| a.cmp(b));
+		self.sort_by(Key::cmp);
 	}
 }
 
-impl<Key, Val: Ord> IndexedMap<Key, Val> {
+impl<Key, Val: Ord, I: KeyIndex<Key>> IndexedMap<Key, Val, I> {
 	pub fn sort_by_value(&mut self) {
 		self.vec.sort_by(|(_, a), (_, b)| a.cmp(b));
+		self.reindex();
 	}
 }
 
-impl<Key: PartialEq, Val> Extend<(Key, Val)> for IndexedMap<Key, Val> {
+impl<Key, Val, I: KeyLookup<Key>> Extend<(Key, Val)> for IndexedMap<Key, Val, I> {
 	fn extend<Iter: IntoIterator<Item = (Key, Val)>>(&mut self, iter: Iter) {
 		for (key, value) in iter {
 			self.push(key, value);
@@ -169,43 +310,43 @@ impl<Key: PartialEq, Val> Extend<(Key, Val)> for IndexedMap<Key, Val> {
 	}
 }
 
-impl<Key: PartialEq, Val> IntoIterator for IndexedMap<Key, Val> {
+impl<Key: PartialEq, Val, I> IntoIterator for IndexedMap<Key, Val, I> {
 	type Item = (Key, Val);
 	type IntoIter = impl Iterator<Item = (Key, Val)>;
 	fn into_iter(self) -> Self::IntoIter { self.vec.into_iter() }
 }
 
-impl<'a, Key, Val> IntoIterator for &'a IndexedMap<Key, Val> {
+impl<'a, Key, Val, I> IntoIterator for &'a IndexedMap<Key, Val, I> {
 	type Item = (&'a Key, &'a Val);
 	type IntoIter = impl Iterator<Item = (&'a Key, &'a Val)>;
 	fn into_iter(self) -> Self::IntoIter { self.iter().map(|(k, v)| (k, v)) }
 }
 
-impl<'a, Key, Val> IntoIterator for &'a mut IndexedMap<Key, Val> {
+impl<'a, Key, Val, I: KeyIndex<Key>> IntoIterator for &'a mut IndexedMap<Key, Val, I> {
 	type Item = (&'a Key, &'a mut Val);
 	type IntoIter = impl Iterator<Item = (&'a Key, &'a mut Val)>;
 	fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
 }
 
-impl<Key, Val> Index<usize> for IndexedMap<Key, Val> {
+impl<Key, Val, I: KeyIndex<Key>> Index<usize> for IndexedMap<Key, Val, I> {
 	type Output = Val;
 	fn index(&self, index: usize) -> &Self::Output { self.value_at(index).unwrap() }
 }
 
-impl<Key: PartialEq, Val> Index<&Key> for IndexedMap<Key, Val> {
+impl<Key, Val, I: KeyLookup<Key>> Index<&Key> for IndexedMap<Key, Val, I> {
 	type Output = Val;
 	fn index(&self, key: &Key) -> &Self::Output { self.get_value(key).unwrap() }
 }
 
-impl<Key, Val> IndexMut<usize> for IndexedMap<Key, Val> {
+impl<Key, Val, I: KeyIndex<Key>> IndexMut<usize> for IndexedMap<Key, Val, I> {
 	fn index_mut(&mut self, index: usize) -> &mut Self::Output { self.value_at_mut(index).unwrap() }
 }
 
-impl<Key: PartialEq, Val> IndexMut<&Key> for IndexedMap<Key, Val> {
+impl<Key, Val, I: KeyLookup<Key>> IndexMut<&Key> for IndexedMap<Key, Val, I> {
 	fn index_mut(&mut self, key: &Key) -> &mut Self::Output { self.get_value_mut(key).unwrap() }
 }
 
-impl<Key: PartialEq, Val> FromIterator<(Key, Val)> for IndexedMap<Key, Val> {
+impl<Key, Val, I: KeyLookup<Key>> FromIterator<(Key, Val)> for IndexedMap<Key, Val, I> {
 	fn from_iter<T: IntoIterator<Item = (Key, Val)>>(into_iter: T) -> Self {
 		let iter = into_iter.into_iter();
 		let size = {
@@ -213,13 +354,152 @@ impl<Key: PartialEq, Val> FromIterator<(Key, Val)> for IndexedMap<Key, Val> {
 			usize::max(lower, upper.unwrap_or(0))
 		};
 
-		let mut result = Self { vec: Vec::with_capacity(size) };
+		let mut result = Self { vec: Vec::with_capacity(size), index: I::default() };
 		result.extend(iter);
 		result.vec.shrink_to_fit();
 		result
 	}
 }
 
+impl<Key, Val, I: KeyLookup<Key>> IndexedMap<Key, Val, I> {
+	/// Gets the entry of `key`, to inspect or modify it in place with a single lookup.
+	pub fn entry(&mut self, key: Key) -> IndexedMapEntry<'_, Key, Val, I> {
+		match self.key_index(&key) {
+			Some(index) => IndexedMapEntry::Occupied(IndexedMapOccupiedEntry { map: self, index }),
+			None => IndexedMapEntry::Vacant(IndexedMapVacantEntry { map: self, key }),
+		}
+	}
+}
+
+/// A view into a single entry of an [`IndexedMap`], see [`IndexedMap::entry`].
+#[derive(Debug)]
+pub enum IndexedMapEntry<'a, Key, Val, I = LinearIndex> {
+	Occupied(IndexedMapOccupiedEntry<'a, Key, Val, I>),
+	Vacant(IndexedMapVacantEntry<'a, Key, Val, I>),
+}
+
+/// An entry whose key is in the map.
+#[derive(Debug)]
+pub struct IndexedMapOccupiedEntry<'a, Key, Val, I = LinearIndex> {
+	map: &'a mut IndexedMap<Key, Val, I>,
+	index: usize,
+}
+
+/// An entry whose key is not in the map, inserting it pushes it at the end.
+#[derive(Debug)]
+pub struct IndexedMapVacantEntry<'a, Key, Val, I = LinearIndex> {
+	map: &'a mut IndexedMap<Key, Val, I>,
+	key: Key,
+}
+
+impl<'a, Key, Val, I: KeyIndex<Key>> IndexedMapEntry<'a, Key, Val, I> {
+	pub fn key(&self) -> &Key {
+		match self {
+			IndexedMapEntry::Occupied(entry) => entry.key(),
+			IndexedMapEntry::Vacant(entry) => entry.key(),
+		}
+	}
+
+	/// The position of the entry, or the position it will have once inserted.
+	pub fn index(&self) -> usize {
+		match self {
+			IndexedMapEntry::Occupied(entry) => entry.index(),
+			IndexedMapEntry::Vacant(entry) => entry.index(),
+		}
+	}
+
+	pub fn or_insert(self, default: Val) -> &'a mut Val {
+		match self {
+			IndexedMapEntry::Occupied(entry) => entry.into_mut(),
+			IndexedMapEntry::Vacant(entry) => entry.insert(default),
+		}
+	}
+
+	pub fn or_insert_with(self, default: impl FnOnce() -> Val) -> &'a mut Val {
+		match self {
+			IndexedMapEntry::Occupied(entry) => entry.into_mut(),
+			IndexedMapEntry::Vacant(entry) => entry.insert(default()),
+		}
+	}
+
+	pub fn or_insert_with_key(self, default: impl FnOnce(&Key) -> Val) -> &'a mut Val {
+		match self {
+			IndexedMapEntry::Occupied(entry) => entry.into_mut(),
+			IndexedMapEntry::Vacant(entry) => {
+				let value = default(&entry.key);
+				entry.insert(value)
+			}
+		}
+	}
+
+	pub fn or_default(self) -> &'a mut Val
+	where Val: Default {
+		self.or_insert_with(Val::default)
+	}
+
+	/// Modifies the value if the key is in the map.
+	pub fn and_modify(mut self, f: impl FnOnce(&mut Val)) -> Self {
+		if let IndexedMapEntry::Occupied(entry) = &mut self {
+			f(entry.get_mut());
+		}
+
+		self
+	}
+}
+
+impl<'a, Key, Val, I: KeyIndex<Key>> IndexedMapOccupiedEntry<'a, Key, Val, I> {
+	pub fn key(&self) -> &Key { &self.map.vec[self.index].0 }
+	pub fn index(&self) -> usize { self.index }
+	pub fn get(&self) -> &Val { &self.map.vec[self.index].1 }
+	pub fn get_mut(&mut self) -> &mut Val { &mut self.map.vec[self.index].1 }
+	pub fn into_mut(self) -> &'a mut Val { &mut self.map.vec[self.index].1 }
+
+	/// Replaces the value, returning the old one.
+	pub fn insert(&mut self, value: Val) -> Val { std::mem::replace(self.get_mut(), value) }
+
+	/// Removes the entry, shifting the ones after it.
+	pub fn remove(self) -> Val { self.remove_entry().1 }
+
+	pub fn remove_entry(self) -> (Key, Val) {
+		let index = self.index;
+		self.map.remove_at(index).unwrap()
+	}
+}
+
+impl<'a, Key, Val, I: KeyIndex<Key>> IndexedMapVacantEntry<'a, Key, Val, I> {
+	pub fn key(&self) -> &Key { &self.key }
+	pub fn into_key(self) -> Key { self.key }
+	pub fn index(&self) -> usize { self.map.len() }
+
+	/// Pushes the entry at the end of the map.
+	pub fn insert(self, value: Val) -> &'a mut Val {
+		let index = self.map.vec.len();
+		self.map.index.inserted(&self.key, index);
+		self.map.vec.push((self.key, value));
+		&mut self.map.vec[index].1
+	}
+}
+
+#[cfg(feature = "proptest")]
+impl<Key: proptest::arbitrary::Arbitrary + PartialEq, Val: proptest::arbitrary::Arbitrary> proptest::arbitrary::Arbitrary for IndexedMap<Key, Val> {
+	type Parameters = <Vec<(Key, Val)> as proptest::arbitrary::Arbitrary>::Parameters;
+	type Strategy = impl proptest::strategy::Strategy<Value = Self>;
+
+	/// Duplicates among the generated entries are merged, so `args` bounds the length from above.
+	fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+		proptest::strategy::Strategy::prop_map(proptest::arbitrary::any_with::<Vec<(Key, Val)>>(args), Self::from_iter)
+	}
+}
+
+#[cfg(feature = "quickcheck")]
+impl<Key: quickcheck::Arbitrary + PartialEq, Val: quickcheck::Arbitrary> quickcheck::Arbitrary for IndexedMap<Key, Val> {
+	fn arbitrary(g: &mut quickcheck::Gen) -> Self { <Vec<(Key, Val)> as quickcheck::Arbitrary>::arbitrary(g).into_iter().collect() }
+
+	fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+		Box::new(quickcheck::Arbitrary::shrink(&self.vec).map(Self::from_iter))
+	}
+}
+
 // Inserting a new key-value pair should add it to the IndexedHashMap
 #[test]
 fn test_insert_new_key_value_pair() {
@@ -389,3 +669,142 @@ fn test_from_iter() {
 	let iter: Vec<(i32, &str)> = vec![(1, "one"), (2, "two"), (3, "three")];
 	let _: IndexedMap<i32, &str> = iter.into_iter().collect();
 }
+
+#[cfg(test)]
+fn assert_hash_index_matches(map: &IndexedMap<i32, &str, HashIndex>) {
+	for (position, key) in map.keys().enumerate() {
+		assert_eq!(map.key_index(key), Some(position));
+	}
+}
+
+#[test]
+fn test_hashed_push_and_replace() {
+	let mut map = IndexedMap::hashed();
+	assert_eq!(map.push(1, "one"), None);
+	assert_eq!(map.push(2, "two"), None);
+	assert_eq!(map.push(3, "three"), None);
+	assert_eq!(map.push(1, "uno"), Some("one"));
+
+	assert_eq!(map.keys().copied().collect::<Vec<_>>(), [2, 3, 1]);
+	assert_eq!(map[&1], "uno");
+	assert!(!map.contains_key(&4));
+	assert_hash_index_matches(&map);
+}
+
+#[test]
+fn test_hashed_positional_changes() {
+	let mut map: IndexedMap<_, _, HashIndex> = (0..8).map(|key| (key, "")).collect();
+
+	map.insert(10, "ten", 2);
+	assert_hash_index_matches(&map);
+	map.insert(20, "twenty", 0);
+	assert_hash_index_matches(&map);
+	assert_eq!(map.remove_at(3), Some((10, "ten")));
+	assert_hash_index_matches(&map);
+	map.swap(0, 4);
+	assert_hash_index_matches(&map);
+	assert_eq!(map.swap_remove(1).0, 0);
+	assert_hash_index_matches(&map);
+	assert_eq!(map.pop().map(|(key, _)| key), Some(6));
+	assert_hash_index_matches(&map);
+	map.truncate(4);
+	assert_hash_index_matches(&map);
+
+	assert_eq!(map.keys().copied().collect::<Vec<_>>(), [3, 7, 1, 2]);
+	assert!(!map.contains_key(&10));
+	assert!(!map.contains_key(&20));
+}
+
+#[test]
+fn test_hashed_reinsert_existing_key() {
+	let mut map: IndexedMap<_, _, HashIndex> = [('a', 1), ('b', 2), ('c', 3)].into_iter().collect();
+
+	assert_eq!(map.insert('a', 10, 3), Some(1));
+	assert_eq!(map.keys().copied().collect::<Vec<_>>(), ['b', 'c', 'a']);
+	assert_eq!(map.key_index(&'b'), Some(0));
+	assert_eq!(map.key_index(&'c'), Some(1));
+	assert_eq!(map.key_index(&'a'), Some(2));
+
+	assert_eq!(map.insert('a', 20, 0), Some(10));
+	assert_eq!(map.keys().copied().collect::<Vec<_>>(), ['a', 'b', 'c']);
+	assert_eq!(map.insert('c', 30, 1), Some(3));
+	assert_eq!(map.keys().copied().collect::<Vec<_>>(), ['a', 'c', 'b']);
+	assert_eq!(map[&'a'], 20);
+	assert_eq!(map[&'c'], 30);
+
+	for (position, key) in map.keys().enumerate() {
+		assert_eq!(map.key_index(key), Some(position));
+	}
+}
+
+#[test]
+fn test_hashed_bulk_changes() {
+	let mut map: IndexedMap<_, _, HashIndex> = (0..10).rev().map(|key| (key, "")).collect();
+
+	map.sort();
+	assert_hash_index_matches(&map);
+	assert_eq!(map.drain(2..5).map(|(key, _)| key).collect::<Vec<_>>(), [2, 3, 4]);
+	assert_hash_index_matches(&map);
+	map.retain(|key, _| key % 2 == 1);
+	assert_hash_index_matches(&map);
+
+	assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 5, 7, 9]);
+	assert!(!map.contains_key(&3));
+
+	let linear: IndexedMap<_, _> = map.reindexed();
+	assert_eq!(linear.key_index(&7), Some(2));
+}
+
+#[test]
+fn test_hashed_leaked_drain() {
+	let mut map: IndexedMap<_, _, HashIndex> = (0..6).map(|key| (key, "")).collect();
+
+	std::mem::forget(map.drain(1..3));
+	assert_hash_index_matches(&map);
+	assert_eq!(map.keys().copied().collect::<Vec<_>>(), [0, 3, 4, 5]);
+	assert!(!map.contains_key(&1));
+	assert_eq!(map.get_value(&5), Some(&""));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_hashed_serde_keeps_format() {
+	let map: IndexedMap<_, _, HashIndex> = [(2, "two"), (1, "one")].into_iter().collect();
+	let text = ron::to_string(&map).unwrap();
+	assert_eq!(text, ron::to_string(&map.clone().reindexed::<LinearIndex>()).unwrap());
+
+	let parsed: IndexedMap<i32, String, HashIndex> = ron::from_str(&text).unwrap();
+	assert_eq!(parsed.key_index(&1), Some(1));
+	assert_eq!(parsed[&2], "two");
+}
+
+#[test]
+fn test_entry() {
+	let mut map = IndexedMap::new();
+	*map.entry("a").or_insert(1) += 10;
+	*map.entry("b").or_default() += 2;
+	map.entry("a").and_modify(|value| *value *= 2).or_insert(0);
+	assert_eq!(map.get_value(&"a"), Some(&22));
+	assert_eq!(map.get_value(&"b"), Some(&2));
+
+	assert_eq!(map.entry("b").index(), 1);
+	assert_eq!(map.entry("c").index(), 2);
+	assert_eq!(map.len(), 2);
+
+	match map.entry("a") {
+		IndexedMapEntry::Occupied(entry) => assert_eq!(entry.remove_entry(), ("a", 22)),
+		IndexedMapEntry::Vacant(_) => panic!("expected an occupied entry"),
+	}
+	assert_eq!(map.key_index(&"b"), Some(0));
+}
+
+#[test]
+fn test_hashed_entry() {
+	let mut map = IndexedMap::hashed();
+	for word in ["x", "y", "x", "z", "x"] {
+		*map.entry(word).or_insert(0) += 1;
+	}
+
+	assert_eq!(map.iter().copied().collect::<Vec<_>>(), [("x", 3), ("y", 1), ("z", 1)]);
+	assert_eq!(map.key_index(&"z"), Some(2));
+}
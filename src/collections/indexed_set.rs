@@ -165,6 +165,26 @@ impl<T: PartialEq> FromIterator<T> for IndexedSet<T> {
 	}
 }
 
+#[cfg(feature = "proptest")]
+impl<T: proptest::arbitrary::Arbitrary + PartialEq> proptest::arbitrary::Arbitrary for IndexedSet<T> {
+	type Parameters = <Vec<T> as proptest::arbitrary::Arbitrary>::Parameters;
+	type Strategy = impl proptest::strategy::Strategy<Value = Self>;
+
+	/// Duplicates among the generated items are merged, so `args` bounds the length from above.
+	fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+		proptest::strategy::Strategy::prop_map(proptest::arbitrary::any_with::<Vec<T>>(args), Self::from_iter)
+	}
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: quickcheck::Arbitrary + PartialEq> quickcheck::Arbitrary for IndexedSet<T> {
+	fn arbitrary(g: &mut quickcheck::Gen) -> Self { <Vec<T> as quickcheck::Arbitrary>::arbitrary(g).into_iter().collect() }
+
+	fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+		Box::new(quickcheck::Arbitrary::shrink(&self.vec).map(Self::from_iter))
+	}
+}
+
 // Inserting a new element into an empty IndexedSet returns None and increases
 // the length of the set by 1
 #[test]
@@ -223,3 +243,33 @@ fn test_from_iter() {
 	let iter: Vec<&str> = vec!["one", "two", "three"];
 	let _: IndexedSet<&str> = iter.into_iter().collect();
 }
+
+#[test]
+fn test_set_algebra_keeps_left_order() {
+	let left: IndexedSet<i32> = [5, 1, 4, 2].into_iter().collect();
+	let right: IndexedSet<i32> = [2, 7, 5, 6].into_iter().collect();
+
+	assert_eq!(left.union(&right).copied().collect::<Vec<_>>(), [5, 1, 4, 2, 7, 6]);
+	assert_eq!(left.intersection(&right).copied().collect::<Vec<_>>(), [5, 2]);
+	assert_eq!(left.difference(&right).copied().collect::<Vec<_>>(), [1, 4]);
+	assert_eq!(left.symmetric_difference(&right).copied().collect::<Vec<_>>(), [1, 4, 7, 6]);
+
+	assert_eq!(*(&left | &right), [5, 1, 4, 2, 7, 6]);
+	assert_eq!(*(&left & &right), [5, 2]);
+	assert_eq!(*(&left - &right), [1, 4]);
+	assert_eq!(*(&left ^ &right), [1, 4, 7, 6]);
+}
+
+#[test]
+fn test_set_algebra_with_other_collections() {
+	let set: IndexedSet<i32> = [3, 1, 2].into_iter().collect();
+	let hash_set: std::collections::HashSet<i32> = [1, 2, 3, 4].into_iter().collect();
+
+	assert!(set.is_subset(&hash_set));
+	assert!(!set.is_superset(&hash_set));
+	assert!(set.is_superset(&vec![2, 3]));
+	assert!(set.is_disjoint(&[7, 8]));
+	assert!(!set.is_disjoint(&[7, 1]));
+	assert_eq!(*(&set - &[1, 9]), [3, 2]);
+	assert_eq!(*(&set & &hash_set), [3, 1, 2]);
+}
@@ -0,0 +1,102 @@
+use std::{
+	collections::HashMap,
+	hash::{BuildHasher, Hash, RandomState},
+};
+
+use smallvec::SmallVec;
+
+/// Keeps track of where each key of an [`IndexedMap`](crate::prelude::IndexedMap) is stored.
+///
+/// The map notifies its index of every change to the positions of its keys.
+pub trait KeyIndex<Key>: Default {
+	/// The key at `index` was added, no other key moved.
+	fn inserted(&mut self, key: &Key, index: usize);
+	/// The key at `index` was removed, no other key moved.
+	fn removed(&mut self, key: &Key, index: usize);
+	/// Every key moved from its position `old` to `new_position(old)`, or was removed if that is `None`.
+	///
+	/// Unlike [`Self::rebuild`], the keys themselves are not needed, so they don't have to be hashed again.
+	fn remapped(&mut self, new_position: impl FnMut(usize) -> Option<usize>);
+	/// Forgets everything, then indexes `keys` along with their positions.
+	fn rebuild<'a>(&mut self, keys: impl Iterator<Item = (usize, &'a Key)>)
+	where Key: 'a;
+}
+
+/// Finds the position of a key among the entries of an [`IndexedMap`](crate::prelude::IndexedMap).
+pub trait KeyLookup<Key>: KeyIndex<Key> {
+	fn find<Val>(&self, entries: &[(Key, Val)], key: &Key) -> Option<usize>;
+}
+
+/// No index, keys are found by comparing them one by one. The default, only requires `Key: PartialEq`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinearIndex;
+
+impl<Key> KeyIndex<Key> for LinearIndex {
+	fn inserted(&mut self, _key: &Key, _index: usize) {}
+	fn removed(&mut self, _key: &Key, _index: usize) {}
+	fn remapped(&mut self, _new_position: impl FnMut(usize) -> Option<usize>) {}
+	fn rebuild<'a>(&mut self, _keys: impl Iterator<Item = (usize, &'a Key)>)
+	where Key: 'a {
+	}
+}
+
+impl<Key: PartialEq> KeyLookup<Key> for LinearIndex {
+	fn find<Val>(&self, entries: &[(Key, Val)], key: &Key) -> Option<usize> {
+		entries.iter().position(|(k, _)| k == key)
+	}
+}
+
+/// Finds keys in constant time by their hash, requires `Key: Hash + Eq`.
+///
+/// Only positions are stored, grouped by the hash of their key, so keys don't need to be `Clone`.
+#[derive(Debug, Clone, Default)]
+pub struct HashIndex {
+	hasher:    RandomState,
+	positions: HashMap<u64, SmallVec<[usize; 1]>>,
+}
+
+impl<Key: Hash + Eq> KeyIndex<Key> for HashIndex {
+	fn inserted(&mut self, key: &Key, index: usize) {
+		let hash = self.hasher.hash_one(key);
+		self.positions.entry(hash).or_default().push(index);
+	}
+
+	fn removed(&mut self, key: &Key, index: usize) {
+		let hash = self.hasher.hash_one(key);
+		if let Some(positions) = self.positions.get_mut(&hash) {
+			positions.retain(|position| *position != index);
+			if positions.is_empty() {
+				self.positions.remove(&hash);
+			}
+		}
+	}
+
+	fn remapped(&mut self, mut new_position: impl FnMut(usize) -> Option<usize>) {
+		self.positions.retain(|_, positions| {
+			positions.retain(|position| match new_position(*position) {
+				Some(new) => {
+					*position = new;
+					true
+				}
+				None => false,
+			});
+			!positions.is_empty()
+		});
+	}
+
+	fn rebuild<'a>(&mut self, keys: impl Iterator<Item = (usize, &'a Key)>)
+	where Key: 'a {
+		self.positions.clear();
+		for (index, key) in keys {
+			self.inserted(key, index);
+		}
+	}
+}
+
+impl<Key: Hash + Eq> KeyLookup<Key> for HashIndex {
+	fn find<Val>(&self, entries: &[(Key, Val)], key: &Key) -> Option<usize> {
+		let hash = self.hasher.hash_one(key);
+		let positions = self.positions.get(&hash)?;
+		positions.iter().copied().find(|position| entries[*position].0 == *key)
+	}
+}
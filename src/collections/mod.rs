@@ -1,13 +1,25 @@
+pub use contains::*;
 pub use count_or_more::*;
 pub use dynamic_array::*;
 pub use indexed_map::*;
 pub use indexed_set::*;
+pub use key_index::*;
+pub use set_algebra::*;
 pub use small_map::*;
 pub use small_set::*;
+pub use sorted::Merged;
+pub use sorted_small_map::*;
+pub use sorted_small_set::*;
 
+mod contains;
 mod count_or_more;
 mod dynamic_array;
 mod indexed_map;
 mod indexed_set;
+mod key_index;
+mod set_algebra;
 mod small_map;
 mod small_set;
+mod sorted;
+mod sorted_small_map;
+mod sorted_small_set;
@@ -0,0 +1,96 @@
+use std::ops::{BitAnd, BitOr, BitXor, Sub};
+
+use crate::prelude::*;
+
+/// Set algebra between a set and any collection implementing [`Contains`].
+///
+/// The iterators are lazy and yield values in the order of `self`, followed by those of `other` where they apply,
+/// so that the operators keep the values of the left operand in their original positions.
+pub trait SetAlgebra<T>: Contains<T> {
+    /// Values in `self` or `other`, without duplicates if `other` has none.
+    fn union<'a, C: Contains<T> + ?Sized>(&'a self, other: &'a C) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.items().chain(other.items().filter(move |value| !self.contains_item(value)))
+    }
+
+    /// Values in both `self` and `other`.
+    fn intersection<'a, C: Contains<T> + ?Sized>(&'a self, other: &'a C) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.items().filter(move |value| other.contains_item(value))
+    }
+
+    /// Values in `self` but not in `other`.
+    fn difference<'a, C: Contains<T> + ?Sized>(&'a self, other: &'a C) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.items().filter(move |value| !other.contains_item(value))
+    }
+
+    /// Values in either `self` or `other`, but not in both.
+    fn symmetric_difference<'a, C: Contains<T> + ?Sized>(&'a self, other: &'a C) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.difference(other).chain(other.items().filter(move |value| !self.contains_item(value)))
+    }
+
+    /// Returns `true` if every value of `self` is in `other`.
+    fn is_subset<C: Contains<T> + ?Sized>(&self, other: &C) -> bool {
+        self.items().all(|value| other.contains_item(value))
+    }
+
+    /// Returns `true` if every value of `other` is in `self`.
+    fn is_superset<C: Contains<T> + ?Sized>(&self, other: &C) -> bool {
+        other.items().all(|value| self.contains_item(value))
+    }
+
+    /// Returns `true` if `self` has no values in common with `other`.
+    fn is_disjoint<C: Contains<T> + ?Sized>(&self, other: &C) -> bool {
+        !self.items().any(|value| other.contains_item(value))
+    }
+}
+
+impl<T: PartialEq, const N: usize> SetAlgebra<T> for SmallSet<T, N> {}
+
+impl<T: PartialEq> SetAlgebra<T> for IndexedSet<T> {}
+
+/// Implements the operators of [`SetAlgebra`] for references to `$set`, collecting the results into a new `$set`.
+macro_rules! impl_set_operators {
+    ([$($generics: tt)*] $set: ty) => {
+        impl<T: PartialEq + Clone, C: Contains<T> + ?Sized, $($generics)*> BitOr<&C> for &$set {
+            type Output = $set;
+
+            /// The union of `self` and `rhs`.
+            fn bitor(self, rhs: &C) -> Self::Output { self.union(rhs).cloned().collect() }
+        }
+
+        impl<T: PartialEq + Clone, C: Contains<T> + ?Sized, $($generics)*> BitAnd<&C> for &$set {
+            type Output = $set;
+
+            /// The intersection of `self` and `rhs`.
+            fn bitand(self, rhs: &C) -> Self::Output { self.intersection(rhs).cloned().collect() }
+        }
+
+        impl<T: PartialEq + Clone, C: Contains<T> + ?Sized, $($generics)*> Sub<&C> for &$set {
+            type Output = $set;
+
+            /// The difference of `self` and `rhs`.
+            fn sub(self, rhs: &C) -> Self::Output { self.difference(rhs).cloned().collect() }
+        }
+
+        impl<T: PartialEq + Clone, C: Contains<T> + ?Sized, $($generics)*> BitXor<&C> for &$set {
+            type Output = $set;
+
+            /// The symmetric difference of `self` and `rhs`.
+            fn bitxor(self, rhs: &C) -> Self::Output { self.symmetric_difference(rhs).cloned().collect() }
+        }
+    };
+}
+
+impl_set_operators!([const N: usize] SmallSet<T, N>);
+impl_set_operators!([] IndexedSet<T>);
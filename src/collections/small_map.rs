@@ -1,12 +1,16 @@
 use std::{
     borrow::Borrow,
+    collections::HashMap,
     fmt::Debug,
+    hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hash, Hasher},
     iter::FusedIterator,
     ops::{Index, IndexMut, RangeBounds},
 };
 
 use smallvec::{CollectionAllocErr, Drain, SmallVec};
 
+use super::{IndexedMap, KeyLookup};
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Default)]
 pub struct SmallMap<K, V, const N: usize>(SmallVec<[(K, V); N]>);
@@ -269,11 +273,58 @@ impl<K: PartialEq, V, const N: usize> SmallMap<K, V, N> {
         self.0.retain_mut(|(k, v)| f(k, v))
     }
 
+    /// Gets the entry of `key`, to inspect or modify it in place with a single lookup.
+    pub fn entry(&mut self, key: K) -> SmallMapEntry<'_, K, V, N> {
+        match self.0.iter().position(|(k, _)| *k == key) {
+            Some(index) => SmallMapEntry::Occupied(SmallMapOccupiedEntry { map: self, index }),
+            None => SmallMapEntry::Vacant(SmallMapVacantEntry { map: self, key }),
+        }
+    }
+
     pub fn iter(&self) -> std::slice::Iter<'_, (K, V)> { self.0.iter() }
 
     pub fn iter_mut(&mut self) -> SmallMapIterMut<'_, K, V> { SmallMapIterMut(self.0.iter_mut()) }
 }
 
+/// Two maps are equal if they hold the same pairs, in any order.
+impl<K: PartialEq, V: PartialEq, const N: usize, const M: usize> PartialEq<SmallMap<K, V, M>> for SmallMap<K, V, N> {
+    fn eq(&self, other: &SmallMap<K, V, M>) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Eq, V: Eq, const N: usize> Eq for SmallMap<K, V, N> {}
+
+/// Consistent with [`PartialEq`], the order of the pairs does not affect the hash.
+impl<K: Hash, V: Hash, const N: usize> Hash for SmallMap<K, V, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hasher = BuildHasherDefault::<DefaultHasher>::default();
+        let sum = self.0.iter().fold(0u64, |sum, pair| sum.wrapping_add(hasher.hash_one(pair)));
+        state.write_usize(self.0.len());
+        state.write_u64(sum);
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq, S: BuildHasher, const N: usize> PartialEq<HashMap<K, V, S>> for SmallMap<K, V, N> {
+    fn eq(&self, other: &HashMap<K, V, S>) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq, S: BuildHasher, const N: usize> PartialEq<SmallMap<K, V, N>> for HashMap<K, V, S> {
+    fn eq(&self, other: &SmallMap<K, V, N>) -> bool { other == self }
+}
+
+impl<K, V: PartialEq, I: KeyLookup<K>, const N: usize> PartialEq<IndexedMap<K, V, I>> for SmallMap<K, V, N> {
+    fn eq(&self, other: &IndexedMap<K, V, I>) -> bool {
+        self.0.len() == other.len() && self.0.iter().all(|(k, v)| other.get_value(k) == Some(v))
+    }
+}
+
+impl<K, V: PartialEq, I: KeyLookup<K>, const N: usize> PartialEq<SmallMap<K, V, N>> for IndexedMap<K, V, I> {
+    fn eq(&self, other: &SmallMap<K, V, N>) -> bool { other == self }
+}
+
 impl<K: PartialEq, V, const N: usize> IntoIterator for SmallMap<K, V, N> {
     type Item = (K, V);
     type IntoIter = smallvec::IntoIter<[(K, V); N]>;
@@ -337,6 +388,127 @@ impl<K: PartialEq, V, const N: usize> Extend<(K, V)> for SmallMap<K, V, N> {
     }
 }
 
+#[cfg(feature = "proptest")]
+impl<K: proptest::arbitrary::Arbitrary + PartialEq, V: proptest::arbitrary::Arbitrary, const N: usize> proptest::arbitrary::Arbitrary for SmallMap<K, V, N> {
+    type Parameters = <Vec<(K, V)> as proptest::arbitrary::Arbitrary>::Parameters;
+    type Strategy = impl proptest::strategy::Strategy<Value = Self>;
+
+    /// Duplicates among the generated entries are merged, so `args` bounds the length from above.
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(proptest::arbitrary::any_with::<Vec<(K, V)>>(args), Self::from_iter)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<K: quickcheck::Arbitrary + PartialEq, V: quickcheck::Arbitrary, const N: usize> quickcheck::Arbitrary for SmallMap<K, V, N> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self { <Vec<(K, V)> as quickcheck::Arbitrary>::arbitrary(g).into_iter().collect() }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(quickcheck::Arbitrary::shrink(&self.0.to_vec()).map(Self::from_iter))
+    }
+}
+
+/// A view into a single entry of a [`SmallMap`], see [`SmallMap::entry`].
+#[derive(Debug)]
+pub enum SmallMapEntry<'a, K, V, const N: usize> {
+    Occupied(SmallMapOccupiedEntry<'a, K, V, N>),
+    Vacant(SmallMapVacantEntry<'a, K, V, N>),
+}
+
+/// An entry whose key is in the map.
+#[derive(Debug)]
+pub struct SmallMapOccupiedEntry<'a, K, V, const N: usize> {
+    map: &'a mut SmallMap<K, V, N>,
+    index: usize,
+}
+
+/// An entry whose key is not in the map.
+#[derive(Debug)]
+pub struct SmallMapVacantEntry<'a, K, V, const N: usize> {
+    map: &'a mut SmallMap<K, V, N>,
+    key: K,
+}
+
+impl<'a, K, V, const N: usize> SmallMapEntry<'a, K, V, N> {
+    pub fn key(&self) -> &K {
+        match self {
+            SmallMapEntry::Occupied(entry) => entry.key(),
+            SmallMapEntry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            SmallMapEntry::Occupied(entry) => entry.into_mut(),
+            SmallMapEntry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            SmallMapEntry::Occupied(entry) => entry.into_mut(),
+            SmallMapEntry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            SmallMapEntry::Occupied(entry) => entry.into_mut(),
+            SmallMapEntry::Vacant(entry) => {
+                let value = default(&entry.key);
+                entry.insert(value)
+            }
+        }
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Modifies the value if the key is in the map.
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let SmallMapEntry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, K, V, const N: usize> SmallMapOccupiedEntry<'a, K, V, N> {
+    pub fn key(&self) -> &K { &self.map.0[self.index].0 }
+
+    pub fn get(&self) -> &V { &self.map.0[self.index].1 }
+
+    pub fn get_mut(&mut self) -> &mut V { &mut self.map.0[self.index].1 }
+
+    pub fn into_mut(self) -> &'a mut V { &mut self.map.0[self.index].1 }
+
+    /// Replaces the value, returning the old one.
+    pub fn insert(&mut self, value: V) -> V { std::mem::replace(self.get_mut(), value) }
+
+    /// Removes the entry, moving the last one into its place like [`SmallMap::remove`].
+    pub fn remove(self) -> V { self.remove_entry().1 }
+
+    pub fn remove_entry(self) -> (K, V) { self.map.0.swap_remove(self.index) }
+}
+
+impl<'a, K, V, const N: usize> SmallMapVacantEntry<'a, K, V, N> {
+    pub fn key(&self) -> &K { &self.key }
+
+    pub fn into_key(self) -> K { self.key }
+
+    /// Pushes the entry at the end of the map.
+    pub fn insert(self, value: V) -> &'a mut V {
+        let index = self.map.0.len();
+        self.map.0.push((self.key, value));
+        &mut self.map.0[index].1
+    }
+}
+
 #[derive(Debug)]
 pub struct SmallMapIterMut<'a, K, V>(std::slice::IterMut<'a, (K, V)>);
 
@@ -1256,4 +1428,81 @@ mod tests {
             assert_eq!(map.get(&i), Some(&(i * 2)));
         }
     }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_proptest_generates_unique_keys(map: SmallMap<u8, u8, 4>) {
+            let keys = map.keys().copied().collect::<std::collections::HashSet<_>>();
+            proptest::prop_assert_eq!(keys.len(), map.len());
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn test_quickcheck_generates_unique_keys() {
+        use quickcheck::{Arbitrary, Gen};
+
+        let mut g = Gen::new(20);
+        for _ in 0..100 {
+            let map = SmallMap::<u8, u8, 4>::arbitrary(&mut g);
+            let keys = map.keys().copied().collect::<std::collections::HashSet<_>>();
+            assert_eq!(keys.len(), map.len());
+            assert!(map.shrink().take(20).all(|shrunk| shrunk.len() <= map.len()));
+        }
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut map = TestMap::new();
+        *map.entry(1).or_insert("one".to_string()) += "!";
+        map.entry(1).and_modify(|v| v.push('?')).or_default();
+        map.entry(2).and_modify(|v| v.push('?')).or_default();
+        assert_eq!(map.get(&1), Some(&"one!?".to_string()));
+        assert_eq!(map.get(&2), Some(&String::new()));
+        assert_eq!(map.entry(3).or_insert_with_key(|key| key.to_string()), "3");
+        assert_eq!(map.len(), 3);
+
+        match map.entry(1) {
+            SmallMapEntry::Occupied(entry) => assert_eq!(entry.remove(), "one!?"),
+            SmallMapEntry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+        assert!(!map.contains_key(&1));
+        assert!(matches!(map.entry(1), SmallMapEntry::Vacant(entry) if *entry.key() == 1));
+    }
+
+    #[test]
+    fn test_eq_ignores_order() {
+        let map: TestMap = [(1, "one".to_string()), (2, "two".to_string())].into_iter().collect();
+        let reversed: SmallMap<i32, String, 4> = [(2, "two".to_string()), (1, "one".to_string())].into_iter().collect();
+        assert_eq!(map, reversed);
+
+        let mut other = reversed.clone();
+        other.insert(2, "dos".to_string());
+        assert_ne!(map, other);
+        other.remove(&2);
+        assert_ne!(map, other);
+    }
+
+    #[test]
+    fn test_hash_ignores_order() {
+        let map: TestMap = [(1, "one".to_string()), (2, "two".to_string())].into_iter().collect();
+        let reversed: TestMap = [(2, "two".to_string()), (1, "one".to_string())].into_iter().collect();
+        let hasher = std::hash::RandomState::new();
+        assert_eq!(hasher.hash_one(&map), hasher.hash_one(&reversed));
+        assert_ne!(hasher.hash_one(&map), hasher.hash_one(TestMap::new()));
+    }
+
+    #[test]
+    fn test_eq_other_maps() {
+        let map: TestMap = [(1, "one".to_string()), (2, "two".to_string())].into_iter().collect();
+        let hash_map: HashMap<i32, String> = map.clone().into_iter().collect();
+        let indexed: IndexedMap<i32, String> = [(2, "two".to_string()), (1, "one".to_string())].into_iter().collect();
+
+        assert_eq!(map, hash_map);
+        assert_eq!(hash_map, map);
+        assert_eq!(map, indexed);
+        assert_eq!(indexed, map);
+        assert_ne!(map, IndexedMap::<i32, String>::new());
+    }
 }
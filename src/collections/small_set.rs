@@ -1,7 +1,15 @@
-use std::{borrow::Borrow, hash::Hash, iter::FusedIterator, mem, ops::RangeBounds};
+use std::{
+    borrow::Borrow,
+    hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hash, Hasher},
+    iter::FusedIterator,
+    mem,
+    ops::RangeBounds,
+};
 
 use smallvec::{CollectionAllocErr, Drain, SmallVec};
 
+use super::SetAlgebra;
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Default)]
 pub struct SmallSet<T, const N: usize>(SmallVec<[T; N]>);
@@ -196,11 +204,38 @@ impl<T: PartialEq, const N: usize> SmallSet<T, N> {
     /// elements.
     pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) { self.0.retain(|t| f(t)) }
 
+    /// Gets the entry of `value`, to inspect or modify it in place with a single lookup.
+    pub fn entry(&mut self, value: T) -> SmallSetEntry<'_, T, N> {
+        match self.0.iter().position(|v| *v == value) {
+            Some(index) => SmallSetEntry::Occupied(SmallSetOccupiedEntry { set: self, index }),
+            None => SmallSetEntry::Vacant(SmallSetVacantEntry { set: self, value }),
+        }
+    }
+
     pub fn iter(&self) -> std::slice::Iter<'_, T> { self.0.iter() }
 
     pub fn iter_mut(&mut self) -> SmallSetIterMut<'_, T> { SmallSetIterMut(self.0.iter_mut()) }
 }
 
+/// Two sets are equal if they hold the same values, in any order.
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<SmallSet<T, M>> for SmallSet<T, N> {
+    fn eq(&self, other: &SmallSet<T, M>) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallSet<T, N> {}
+
+/// Consistent with [`PartialEq`], the order of the values does not affect the hash.
+impl<T: Hash, const N: usize> Hash for SmallSet<T, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let hasher = BuildHasherDefault::<DefaultHasher>::default();
+        let sum = self.0.iter().fold(0u64, |sum, value| sum.wrapping_add(hasher.hash_one(value)));
+        state.write_usize(self.0.len());
+        state.write_u64(sum);
+    }
+}
+
 impl<T: PartialEq, const N: usize> IntoIterator for SmallSet<T, N> {
     type Item = T;
     type IntoIter = smallvec::IntoIter<[T; N]>;
@@ -240,6 +275,99 @@ impl<T: PartialEq, const N: usize> Extend<T> for SmallSet<T, N> {
     }
 }
 
+#[cfg(feature = "proptest")]
+impl<T: proptest::arbitrary::Arbitrary + PartialEq, const N: usize> proptest::arbitrary::Arbitrary for SmallSet<T, N> {
+    type Parameters = <Vec<T> as proptest::arbitrary::Arbitrary>::Parameters;
+    type Strategy = impl proptest::strategy::Strategy<Value = Self>;
+
+    /// Duplicates among the generated items are merged, so `args` bounds the length from above.
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(proptest::arbitrary::any_with::<Vec<T>>(args), Self::from_iter)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: quickcheck::Arbitrary + PartialEq, const N: usize> quickcheck::Arbitrary for SmallSet<T, N> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self { <Vec<T> as quickcheck::Arbitrary>::arbitrary(g).into_iter().collect() }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(quickcheck::Arbitrary::shrink(&self.0.to_vec()).map(Self::from_iter))
+    }
+}
+
+/// A view into a single value of a [`SmallSet`], see [`SmallSet::entry`].
+#[derive(Debug)]
+pub enum SmallSetEntry<'a, T, const N: usize> {
+    Occupied(SmallSetOccupiedEntry<'a, T, N>),
+    Vacant(SmallSetVacantEntry<'a, T, N>),
+}
+
+/// An entry whose value is in the set.
+#[derive(Debug)]
+pub struct SmallSetOccupiedEntry<'a, T, const N: usize> {
+    set: &'a mut SmallSet<T, N>,
+    index: usize,
+}
+
+/// An entry whose value is not in the set.
+#[derive(Debug)]
+pub struct SmallSetVacantEntry<'a, T, const N: usize> {
+    set: &'a mut SmallSet<T, N>,
+    value: T,
+}
+
+impl<'a, T, const N: usize> SmallSetEntry<'a, T, N> {
+    pub fn get(&self) -> &T {
+        match self {
+            SmallSetEntry::Occupied(entry) => entry.get(),
+            SmallSetEntry::Vacant(entry) => entry.get(),
+        }
+    }
+
+    /// Inserts the value if it is not in the set, returning the one in the set.
+    pub fn or_insert(self) -> &'a mut T {
+        match self {
+            SmallSetEntry::Occupied(entry) => entry.into_mut(),
+            SmallSetEntry::Vacant(entry) => entry.insert(),
+        }
+    }
+
+    /// Modifies the value in the set, if there is one.
+    ///
+    /// The modified value must not become equal to another value in the set.
+    pub fn and_modify<F: FnOnce(&mut T)>(mut self, f: F) -> Self {
+        if let SmallSetEntry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, T, const N: usize> SmallSetOccupiedEntry<'a, T, N> {
+    pub fn get(&self) -> &T { &self.set.0[self.index] }
+
+    pub fn get_mut(&mut self) -> &mut T { &mut self.set.0[self.index] }
+
+    pub fn into_mut(self) -> &'a mut T { &mut self.set.0[self.index] }
+
+    /// Removes the value, moving the last one into its place like [`SmallSet::remove`].
+    pub fn remove(self) -> T { self.set.0.swap_remove(self.index) }
+}
+
+impl<'a, T, const N: usize> SmallSetVacantEntry<'a, T, N> {
+    pub fn get(&self) -> &T { &self.value }
+
+    pub fn into_value(self) -> T { self.value }
+
+    /// Pushes the value at the end of the set.
+    pub fn insert(self) -> &'a mut T {
+        let index = self.set.0.len();
+        self.set.0.push(self.value);
+        &mut self.set.0[index]
+    }
+}
+
 #[derive(Debug)]
 pub struct SmallSetIterMut<'a, T>(std::slice::IterMut<'a, T>);
 
@@ -1167,4 +1295,80 @@ mod tests {
         // In a real scenario, we'd move this iterator to another thread
         drop(iter);
     }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn test_quickcheck_generates_unique_items() {
+        use quickcheck::{Arbitrary, Gen};
+
+        let mut g = Gen::new(20);
+        for _ in 0..100 {
+            let set = SmallSet::<u8, 4>::arbitrary(&mut g);
+            let unique = set.iter().copied().collect::<std::collections::HashSet<_>>();
+            assert_eq!(unique.len(), set.len());
+        }
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut set = TestSet::new();
+        assert_eq!(*set.entry(1).or_insert(), 1);
+        assert_eq!(*set.entry(1).or_insert(), 1);
+        assert_eq!(set.len(), 1);
+
+        match set.entry(1).and_modify(|value| *value = 5) {
+            SmallSetEntry::Occupied(entry) => assert_eq!(entry.remove(), 5),
+            SmallSetEntry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+        assert!(set.is_empty());
+
+        match set.entry(2) {
+            SmallSetEntry::Vacant(entry) => assert_eq!(entry.into_value(), 2),
+            SmallSetEntry::Occupied(_) => panic!("expected a vacant entry"),
+        }
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let left: SmallSet<i32, 4> = [1, 2, 3, 4].into_iter().collect();
+        let right: SmallSet<i32, 2> = [3, 4, 5].into_iter().collect();
+
+        assert_eq!(left.union(&right).copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+        assert_eq!(left.intersection(&right).copied().collect::<Vec<_>>(), [3, 4]);
+        assert_eq!(left.difference(&right).copied().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(left.symmetric_difference(&right).copied().collect::<Vec<_>>(), [1, 2, 5]);
+
+        assert_eq!((&left | &right).as_slice(), [1, 2, 3, 4, 5]);
+        assert_eq!((&left & &right).as_slice(), [3, 4]);
+        assert_eq!((&left - &right).as_slice(), [1, 2]);
+        assert_eq!((&left ^ &right).as_slice(), [1, 2, 5]);
+    }
+
+    #[test]
+    fn test_set_predicates() {
+        let set: TestSet = [1, 2].into_iter().collect();
+        let btree: std::collections::BTreeSet<i32> = [0, 1, 2].into_iter().collect();
+
+        assert!(set.is_subset(&btree));
+        assert!(!set.is_superset(&btree));
+        assert!(set.is_superset(&[2]));
+        assert!(set.is_disjoint(&vec![3, 4]));
+        assert!(!set.is_disjoint(&set));
+        assert_eq!((&set | &btree).len(), 3);
+    }
+
+    #[test]
+    fn test_eq_and_hash_ignore_order() {
+        let set: TestSet = [1, 2, 3].into_iter().collect();
+        let reversed: SmallSet<i32, 8> = [3, 2, 1].into_iter().collect();
+        assert_eq!(set, reversed);
+        assert_ne!(set, [1, 2].into_iter().collect::<TestSet>());
+        assert_ne!(set, [1, 2, 4].into_iter().collect::<TestSet>());
+
+        let mut keys = std::collections::HashSet::new();
+        keys.insert(set);
+        assert!(keys.contains(&[2, 3, 1].into_iter().collect::<TestSet>()));
+        assert!(!keys.contains(&[2, 3].into_iter().collect::<TestSet>()));
+    }
 }
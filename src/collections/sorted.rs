@@ -0,0 +1,90 @@
+use std::{
+    cmp::Ordering,
+    ops::{Bound, Range, RangeBounds},
+};
+
+/// An item of an ordered merge between two sorted collections, telling which side(s) it came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Merged<L, R> {
+    /// Only in the left collection.
+    Left(L),
+    /// Only in the right collection.
+    Right(R),
+    /// In both collections.
+    Both(L, R),
+}
+
+impl<L, R> Merged<L, R> {
+    pub fn left(self) -> Option<L> {
+        match self {
+            Merged::Left(left) | Merged::Both(left, _) => Some(left),
+            Merged::Right(_) => None,
+        }
+    }
+
+    pub fn right(self) -> Option<R> {
+        match self {
+            Merged::Right(right) | Merged::Both(_, right) => Some(right),
+            Merged::Left(_) => None,
+        }
+    }
+}
+
+impl<T> Merged<T, T> {
+    /// The item, preferring the left one when it is in both collections.
+    pub fn into_inner(self) -> T {
+        match self {
+            Merged::Left(item) | Merged::Right(item) | Merged::Both(item, _) => item,
+        }
+    }
+}
+
+/// Lazily merges two iterators sorted by `compare`, pairing up the items that compare equal.
+pub(crate) fn merge_sorted<L, R>(
+    left: impl Iterator<Item = L>,
+    right: impl Iterator<Item = R>,
+    mut compare: impl FnMut(&L, &R) -> Ordering,
+) -> impl Iterator<Item = Merged<L, R>> {
+    std::iter::from_coroutine(
+        #[coroutine]
+        move || {
+            let mut left = left.peekable();
+            let mut right = right.peekable();
+
+            loop {
+                let ordering = match (left.peek(), right.peek()) {
+                    (Some(l), Some(r)) => compare(l, r),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => return,
+                };
+
+                match ordering {
+                    Ordering::Less => yield Merged::Left(left.next().unwrap()),
+                    Ordering::Greater => yield Merged::Right(right.next().unwrap()),
+                    Ordering::Equal => yield Merged::Both(left.next().unwrap(), right.next().unwrap()),
+                }
+            }
+        },
+    )
+}
+
+/// The indices of the items of `sorted` whose key falls within `range`, empty if `range` is inverted.
+pub(crate) fn range_indices<T, Q: Ord + ?Sized>(
+    sorted: &[T],
+    key: impl Fn(&T) -> &Q,
+    range: impl RangeBounds<Q>,
+) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => sorted.partition_point(|item| key(item) < start),
+        Bound::Excluded(start) => sorted.partition_point(|item| key(item) <= start),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => sorted.partition_point(|item| key(item) <= end),
+        Bound::Excluded(end) => sorted.partition_point(|item| key(item) < end),
+        Bound::Unbounded => sorted.len(),
+    };
+
+    start..end.max(start)
+}
@@ -0,0 +1,405 @@
+use std::{
+    borrow::Borrow,
+    fmt::Debug,
+    ops::{Index, IndexMut, RangeBounds},
+};
+
+use smallvec::{CollectionAllocErr, SmallVec};
+
+use super::sorted::{merge_sorted, range_indices};
+use crate::prelude::*;
+
+/// A [`SmallMap`] that keeps its entries sorted by key.
+///
+/// Lookups, insertions and removals use binary search instead of a linear scan,
+/// which pays off once the map grows past a handful of entries or spills onto the heap.
+#[derive(Debug, Clone, Default)]
+pub struct SortedSmallMap<K, V, const N: usize>(SmallVec<[(K, V); N]>);
+
+impl<K: Ord, V, const N: usize> SortedSmallMap<K, V, N> {
+    /// Construct an empty map
+    #[inline]
+    pub fn new() -> Self { Self(SmallVec::new()) }
+
+    /// Construct an empty map with enough capacity pre-allocated to store at least `n`
+    /// entries.
+    ///
+    /// Will create a heap allocation only if `n` is larger than the inline capacity.
+    #[inline]
+    pub fn with_capacity(n: usize) -> Self { Self(SmallVec::with_capacity(n)) }
+
+    /// The number of entries stored in the map
+    #[inline]
+    pub fn len(&self) -> usize { self.0.len() }
+
+    /// Returns `true` if the map is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.0.is_empty() }
+
+    /// The number of entries the map can hold without reallocating
+    #[inline]
+    pub fn capacity(&self) -> usize { self.0.capacity() }
+
+    /// Returns `true` if the data has spilled into a separate heap-allocated buffer.
+    #[inline]
+    pub fn spilled(&self) -> bool { self.0.spilled() }
+
+    /// An iterator visiting all keys in ascending order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator { self.0.iter().map(|(k, _)| k) }
+
+    /// An iterator visiting all values in ascending order of their keys.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator { self.0.iter().map(|(_, v)| v) }
+
+    /// An iterator visiting all values mutably in ascending order of their keys.
+    pub fn values_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut V> + ExactSizeIterator {
+        self.0.iter_mut().map(|(_, v)| v)
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but
+    /// [`Ord`] on the borrowed form *must* match the one for the key type.
+    #[inline]
+    pub fn get<Q: Ord + ?Sized>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        self.search(k).ok().map(|index| &self.0[index].1)
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    #[inline]
+    pub fn get_key_value<Q: Ord + ?Sized>(&self, k: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+    {
+        self.search(k).ok().map(|index| (&self.0[index].0, &self.0[index].1))
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    #[inline]
+    pub fn get_mut<Q: Ord + ?Sized>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        self.search(k).ok().map(|index| &mut self.0[index].1)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key.
+    #[inline]
+    pub fn contains_key<Q: Ord + ?Sized>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.search(k).is_ok()
+    }
+
+    /// Inserts an entry at the position that keeps the map sorted,
+    /// returning the old value if the key was already present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.search(&key) {
+            Ok(index) => Some(std::mem::replace(&mut self.0[index].1, value)),
+            Err(index) => {
+                self.0.insert(index, (key, value));
+                None
+            }
+        }
+    }
+
+    /// Remove and return the value stored in the key `key`.
+    pub fn remove<Q: Ord + ?Sized>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(k).map(|(_, v)| v)
+    }
+
+    /// Remove and return the entry stored in the key `key`.
+    pub fn remove_entry<Q: Ord + ?Sized>(&mut self, k: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        self.search(k).ok().map(|index| self.0.remove(index))
+    }
+
+    /// The entry with the smallest key.
+    pub fn first(&self) -> Option<(&K, &V)> { self.0.first().map(|(k, v)| (k, v)) }
+
+    /// The entry with the largest key.
+    pub fn last(&self) -> Option<(&K, &V)> { self.0.last().map(|(k, v)| (k, v)) }
+
+    /// Removes the entry with the smallest key.
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        if self.0.is_empty() {
+            None
+        } else {
+            Some(self.0.remove(0))
+        }
+    }
+
+    /// Removes the entry with the largest key.
+    pub fn pop_last(&mut self) -> Option<(K, V)> { self.0.pop() }
+
+    /// The entries whose keys fall within `range`, in ascending order.
+    ///
+    /// An inverted range yields nothing.
+    pub fn range<Q: Ord + ?Sized, R: RangeBounds<Q>>(&self, range: R) -> std::slice::Iter<'_, (K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        self.0[range_indices(&self.0, |(k, _)| k.borrow(), range)].iter()
+    }
+
+    /// Mutable access to the values of the entries whose keys fall within `range`, in ascending order.
+    pub fn range_mut<Q: Ord + ?Sized, R: RangeBounds<Q>>(
+        &mut self,
+        range: R,
+    ) -> impl DoubleEndedIterator<Item = (&K, &mut V)> + ExactSizeIterator
+    where
+        K: Borrow<Q>,
+    {
+        let indices = range_indices(&self.0, |(k, _)| k.borrow(), range);
+        self.0[indices].iter_mut().map(|(k, v)| (&*k, v))
+    }
+
+    /// Iterates over the entries of both maps in ascending order of their keys,
+    /// pairing up the values of keys present in both.
+    pub fn merge<'a, W, const M: usize>(
+        &'a self,
+        other: &'a SortedSmallMap<K, W, M>,
+    ) -> impl Iterator<Item = (&'a K, Merged<&'a V, &'a W>)> {
+        merge_sorted(self.0.iter(), other.0.iter(), |(a, _), (b, _)| a.cmp(b)).map(|merged| match merged {
+            Merged::Left((k, v)) => (k, Merged::Left(v)),
+            Merged::Right((k, w)) => (k, Merged::Right(w)),
+            Merged::Both((k, v), (_, w)) => (k, Merged::Both(v, w)),
+        })
+    }
+
+    /// Reserve capacity for `additional` more entries to be inserted.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) { self.0.reserve(additional) }
+
+    /// Reserve capacity for `additional` more entries to be inserted.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionAllocErr> {
+        self.0.try_reserve(additional)
+    }
+
+    /// Shrink the capacity of the map as much as possible.
+    pub fn shrink_to_fit(&mut self) { self.0.shrink_to_fit(); }
+
+    /// Extracts a slice of the entire map, sorted by key.
+    pub fn as_slice(&self) -> &[(K, V)] { &self.0 }
+
+    /// Remove all entries from the map.
+    #[inline]
+    pub fn clear(&mut self) { self.0.clear(); }
+
+    /// Convert to a `Vec` sorted by key, without reallocating if the map has already spilled onto
+    /// the heap.
+    pub fn into_vec(self) -> Vec<(K, V)> { self.0.into_vec() }
+
+    /// Retains only the entries specified by the predicate.
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.0.retain_mut(|(k, v)| f(k, v))
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, (K, V)> { self.0.iter() }
+
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (&K, &mut V)> + ExactSizeIterator {
+        self.0.iter_mut().map(|(k, v)| (&*k, v))
+    }
+
+    fn search<Q: Ord + ?Sized>(&self, k: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+    {
+        self.0.binary_search_by(|(key, _)| key.borrow().cmp(k))
+    }
+
+    /// Restores the order after unsorted entries were pushed, the last entry of each key wins.
+    fn sort_and_dedup(&mut self) {
+        self.0.reverse();
+        self.0.sort_by(|(a, _), (b, _)| a.cmp(b));
+        self.0.dedup_by(|(a, _), (b, _)| a == b);
+    }
+}
+
+impl<K: Ord, V, const N: usize> IntoIterator for SortedSmallMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = smallvec::IntoIter<[(K, V); N]>;
+
+    fn into_iter(self) -> Self::IntoIter { self.0.into_iter() }
+}
+
+impl<'a, K: Ord, V, const N: usize> IntoIterator for &'a SortedSmallMap<K, V, N> {
+    type Item = &'a (K, V);
+    type IntoIter = std::slice::Iter<'a, (K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<K, V, Q, const N: usize> Index<&Q> for SortedSmallMap<K, V, N>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + Debug + ?Sized,
+{
+    type Output = V;
+
+    fn index(&self, index: &Q) -> &Self::Output {
+        self.get(index)
+            .unwrap_or_else(|| panic!("No entry found for key {index:?}"))
+    }
+}
+
+impl<K, V, Q, const N: usize> IndexMut<&Q> for SortedSmallMap<K, V, N>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + Debug + ?Sized,
+{
+    fn index_mut(&mut self, index: &Q) -> &mut Self::Output {
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("No entry found for key {index:?}"))
+    }
+}
+
+impl<K: Ord, V, const N: usize> FromIterator<(K, V)> for SortedSmallMap<K, V, N> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self(iter.into_iter().collect());
+        map.sort_and_dedup();
+        map
+    }
+}
+
+impl<K: Ord, V, const N: usize> Extend<(K, V)> for SortedSmallMap<K, V, N> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter);
+        self.sort_and_dedup();
+    }
+}
+
+impl<K: Ord, V, const N: usize> From<SmallMap<K, V, N>> for SortedSmallMap<K, V, N> {
+    fn from(map: SmallMap<K, V, N>) -> Self { map.into_iter().collect() }
+}
+
+#[cfg(feature = "serde")]
+impl<K: serde::Serialize, V: serde::Serialize, const N: usize> serde::Serialize for SortedSmallMap<K, V, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.as_slice().serialize(serializer)
+    }
+}
+
+/// Accepts entries in any order, sorting them and keeping the last value of duplicate keys.
+#[cfg(feature = "serde")]
+impl<'de, K: serde::Deserialize<'de> + Ord, V: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for SortedSmallMap<K, V, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<(K, V)>::deserialize(deserializer)?.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<K: proptest::arbitrary::Arbitrary + Ord, V: proptest::arbitrary::Arbitrary, const N: usize> proptest::arbitrary::Arbitrary for SortedSmallMap<K, V, N> {
+    type Parameters = <Vec<(K, V)> as proptest::arbitrary::Arbitrary>::Parameters;
+    type Strategy = impl proptest::strategy::Strategy<Value = Self>;
+
+    /// Duplicates among the generated entries are merged, so `args` bounds the length from above.
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(proptest::arbitrary::any_with::<Vec<(K, V)>>(args), Self::from_iter)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<K: quickcheck::Arbitrary + Ord, V: quickcheck::Arbitrary, const N: usize> quickcheck::Arbitrary for SortedSmallMap<K, V, N> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self { <Vec<(K, V)> as quickcheck::Arbitrary>::arbitrary(g).into_iter().collect() }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(quickcheck::Arbitrary::shrink(&self.0.to_vec()).map(Self::from_iter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestMap = SortedSmallMap<i32, &'static str, 4>;
+
+    fn keys(map: &TestMap) -> Vec<i32> { map.keys().copied().collect() }
+
+    #[test]
+    fn test_insert_keeps_order() {
+        let mut map = TestMap::new();
+        assert_eq!(map.insert(5, "five"), None);
+        assert_eq!(map.insert(1, "one"), None);
+        assert_eq!(map.insert(3, "three"), None);
+        assert_eq!(map.insert(1, "uno"), Some("one"));
+
+        assert_eq!(keys(&map), [1, 3, 5]);
+        assert_eq!(map.get(&1), Some(&"uno"));
+        assert_eq!(map[&5], "five");
+        assert!(!map.contains_key(&2));
+    }
+
+    #[test]
+    fn test_remove_and_pop() {
+        let mut map: TestMap = [(4, "four"), (2, "two"), (8, "eight"), (6, "six")].into_iter().collect();
+        assert_eq!(map.remove(&6), Some("six"));
+        assert_eq!(map.remove(&6), None);
+        assert_eq!(map.first(), Some((&2, &"two")));
+        assert_eq!(map.last(), Some((&8, &"eight")));
+        assert_eq!(map.pop_first(), Some((2, "two")));
+        assert_eq!(map.pop_last(), Some((8, "eight")));
+        assert_eq!(keys(&map), [4]);
+    }
+
+    #[test]
+    fn test_from_iter_keeps_last_duplicate() {
+        let mut map: TestMap = [(3, "a"), (1, "b"), (3, "c"), (2, "d")].into_iter().collect();
+        assert_eq!(keys(&map), [1, 2, 3]);
+        assert_eq!(map[&3], "c");
+
+        map.extend([(2, "e"), (0, "f")]);
+        assert_eq!(keys(&map), [0, 1, 2, 3]);
+        assert_eq!(map[&2], "e");
+    }
+
+    #[test]
+    fn test_range() {
+        let mut map: SortedSmallMap<i32, i32, 2> = (0..10).map(|key| (key * 10, key)).collect();
+        assert!(map.spilled());
+
+        let keys = |range: std::slice::Iter<'_, (i32, i32)>| range.map(|(k, _)| *k).collect::<Vec<_>>();
+        assert_eq!(keys(map.range(15..40)), [20, 30]);
+        assert_eq!(keys(map.range(20..=40)), [20, 30, 40]);
+        assert_eq!(keys(map.range(..20)), [0, 10]);
+        assert_eq!(keys(map.range(85..)), [90]);
+        let (start, end) = (50, 10);
+        assert_eq!(keys(map.range(start..end)), []);
+
+        for (_, value) in map.range_mut(70..) {
+            *value = -1;
+        }
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 6, -1, -1, -1]);
+    }
+
+    #[test]
+    fn test_merge() {
+        let left: TestMap = [(1, "a"), (3, "c"), (4, "d")].into_iter().collect();
+        let right: SortedSmallMap<i32, u8, 2> = [(2, 20), (3, 30), (5, 50)].into_iter().collect();
+
+        let merged = left.merge(&right).collect::<Vec<_>>();
+        assert_eq!(merged, [
+            (&1, Merged::Left(&"a")),
+            (&2, Merged::Right(&20)),
+            (&3, Merged::Both(&"c", &30)),
+            (&4, Merged::Left(&"d")),
+            (&5, Merged::Right(&50)),
+        ]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_sorts_input() {
+        let map: TestMap = ron::from_str(r#"[(2, "b"), (1, "a")]"#).unwrap();
+        assert_eq!(keys(&map), [1, 2]);
+        assert_eq!(ron::to_string(&map).unwrap(), r#"[(1,"a"),(2,"b")]"#);
+    }
+}
@@ -0,0 +1,295 @@
+use std::{borrow::Borrow, ops::RangeBounds};
+
+use smallvec::{CollectionAllocErr, SmallVec};
+
+use super::sorted::{merge_sorted, range_indices};
+use crate::prelude::*;
+
+/// A [`SmallSet`] that keeps its values sorted.
+///
+/// Lookups, insertions and removals use binary search instead of a linear scan,
+/// which pays off once the set grows past a handful of values or spills onto the heap.
+#[derive(Debug, Clone, Default)]
+pub struct SortedSmallSet<T, const N: usize>(SmallVec<[T; N]>);
+
+impl<T: Ord, const N: usize> SortedSmallSet<T, N> {
+    /// Construct an empty set
+    #[inline]
+    pub fn new() -> Self { Self(SmallVec::new()) }
+
+    /// Construct an empty set with enough capacity pre-allocated to store at least `n`
+    /// values.
+    ///
+    /// Will create a heap allocation only if `n` is larger than the inline capacity.
+    #[inline]
+    pub fn with_capacity(n: usize) -> Self { Self(SmallVec::with_capacity(n)) }
+
+    /// The number of values stored in the set
+    #[inline]
+    pub fn len(&self) -> usize { self.0.len() }
+
+    /// Returns `true` if the set is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.0.is_empty() }
+
+    /// The number of values the set can hold without reallocating
+    #[inline]
+    pub fn capacity(&self) -> usize { self.0.capacity() }
+
+    /// Returns `true` if the data has spilled into a separate heap-allocated buffer.
+    #[inline]
+    pub fn spilled(&self) -> bool { self.0.spilled() }
+
+    /// Returns `true` if the set contains a value.
+    ///
+    /// The value may be any borrowed form of the set's value type, but
+    /// [`Ord`] on the borrowed form *must* match the one for the value type.
+    #[inline]
+    pub fn contains<Q: Ord + ?Sized>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.search(value).is_ok()
+    }
+
+    /// Returns a reference to the value in the set, if any, that is equal to the given value.
+    #[inline]
+    pub fn get<Q: Ord + ?Sized>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+    {
+        self.search(value).ok().map(|index| &self.0[index])
+    }
+
+    /// Inserts a value at the position that keeps the set sorted,
+    /// replacing and returning the equal value if there was one.
+    pub fn insert(&mut self, value: T) -> Option<T> {
+        match self.search(&value) {
+            Ok(index) => Some(std::mem::replace(&mut self.0[index], value)),
+            Err(index) => {
+                self.0.insert(index, value);
+                None
+            }
+        }
+    }
+
+    /// Remove and return the value equal to `value`.
+    pub fn remove<Q: Ord + ?Sized>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
+        self.search(value).ok().map(|index| self.0.remove(index))
+    }
+
+    /// The smallest value.
+    pub fn first(&self) -> Option<&T> { self.0.first() }
+
+    /// The largest value.
+    pub fn last(&self) -> Option<&T> { self.0.last() }
+
+    /// Removes the smallest value.
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.0.is_empty() {
+            None
+        } else {
+            Some(self.0.remove(0))
+        }
+    }
+
+    /// Removes the largest value.
+    pub fn pop_last(&mut self) -> Option<T> { self.0.pop() }
+
+    /// The values that fall within `range`, in ascending order.
+    ///
+    /// An inverted range yields nothing.
+    pub fn range<Q: Ord + ?Sized, R: RangeBounds<Q>>(&self, range: R) -> std::slice::Iter<'_, T>
+    where
+        T: Borrow<Q>,
+    {
+        self.0[range_indices(&self.0, T::borrow, range)].iter()
+    }
+
+    /// Iterates over the values of both sets in ascending order,
+    /// pairing up the values present in both.
+    pub fn merge<'a, const M: usize>(&'a self, other: &'a SortedSmallSet<T, M>) -> impl Iterator<Item = Merged<&'a T, &'a T>> {
+        merge_sorted(self.0.iter(), other.0.iter(), |a, b| a.cmp(b))
+    }
+
+    /// Reserve capacity for `additional` more values to be inserted.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) { self.0.reserve(additional) }
+
+    /// Reserve capacity for `additional` more values to be inserted.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionAllocErr> {
+        self.0.try_reserve(additional)
+    }
+
+    /// Shrink the capacity of the set as much as possible.
+    pub fn shrink_to_fit(&mut self) { self.0.shrink_to_fit(); }
+
+    /// Extracts a sorted slice of the entire set.
+    pub fn as_slice(&self) -> &[T] { &self.0 }
+
+    /// Remove all values from the set.
+    #[inline]
+    pub fn clear(&mut self) { self.0.clear(); }
+
+    /// Convert to a sorted `Vec`, without reallocating if the set has already spilled onto
+    /// the heap.
+    pub fn into_vec(self) -> Vec<T> { self.0.into_vec() }
+
+    /// Retains only the values specified by the predicate.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) { self.0.retain(|t| f(t)) }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> { self.0.iter() }
+
+    fn search<Q: Ord + ?Sized>(&self, value: &Q) -> Result<usize, usize>
+    where
+        T: Borrow<Q>,
+    {
+        self.0.binary_search_by(|item| item.borrow().cmp(value))
+    }
+
+    /// Restores the order after unsorted values were pushed, the last of each equal value wins.
+    fn sort_and_dedup(&mut self) {
+        self.0.reverse();
+        self.0.sort();
+        self.0.dedup();
+    }
+}
+
+impl<T: Ord, const N: usize> IntoIterator for SortedSmallSet<T, N> {
+    type Item = T;
+    type IntoIter = smallvec::IntoIter<[T; N]>;
+
+    fn into_iter(self) -> Self::IntoIter { self.0.into_iter() }
+}
+
+impl<'a, T: Ord, const N: usize> IntoIterator for &'a SortedSmallSet<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<T: Ord, const N: usize> FromIterator<T> for SortedSmallSet<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self(iter.into_iter().collect());
+        set.sort_and_dedup();
+        set
+    }
+}
+
+impl<T: Ord, const N: usize> Extend<T> for SortedSmallSet<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+        self.sort_and_dedup();
+    }
+}
+
+impl<T: Ord, const N: usize> From<SmallSet<T, N>> for SortedSmallSet<T, N> {
+    fn from(set: SmallSet<T, N>) -> Self { set.into_iter().collect() }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for SortedSmallSet<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.as_slice().serialize(serializer)
+    }
+}
+
+/// Accepts values in any order, sorting them and dropping duplicates.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + Ord, const N: usize> serde::Deserialize<'de> for SortedSmallSet<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<T>::deserialize(deserializer)?.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<T: proptest::arbitrary::Arbitrary + Ord, const N: usize> proptest::arbitrary::Arbitrary for SortedSmallSet<T, N> {
+    type Parameters = <Vec<T> as proptest::arbitrary::Arbitrary>::Parameters;
+    type Strategy = impl proptest::strategy::Strategy<Value = Self>;
+
+    /// Duplicates among the generated values are merged, so `args` bounds the length from above.
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(proptest::arbitrary::any_with::<Vec<T>>(args), Self::from_iter)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<T: quickcheck::Arbitrary + Ord, const N: usize> quickcheck::Arbitrary for SortedSmallSet<T, N> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self { <Vec<T> as quickcheck::Arbitrary>::arbitrary(g).into_iter().collect() }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(quickcheck::Arbitrary::shrink(&self.0.to_vec()).map(Self::from_iter))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound;
+
+    use super::*;
+
+    type TestSet = SortedSmallSet<i32, 4>;
+
+    fn values(set: &TestSet) -> Vec<i32> { set.iter().copied().collect() }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut set = TestSet::new();
+        assert_eq!(set.insert(7), None);
+        assert_eq!(set.insert(-2), None);
+        assert_eq!(set.insert(3), None);
+        assert_eq!(set.insert(3), Some(3));
+        assert_eq!(values(&set), [-2, 3, 7]);
+
+        assert!(set.contains(&7));
+        assert_eq!(set.remove(&7), Some(7));
+        assert!(!set.contains(&7));
+        assert_eq!(set.first(), Some(&-2));
+        assert_eq!(set.last(), Some(&3));
+        assert_eq!(set.pop_first(), Some(-2));
+        assert_eq!(set.pop_last(), Some(3));
+        assert_eq!(set.pop_last(), None);
+    }
+
+    #[test]
+    fn test_from_iter_and_extend() {
+        let mut set: TestSet = [5, 1, 5, 3, 1].into_iter().collect();
+        assert_eq!(values(&set), [1, 3, 5]);
+
+        set.extend([4, 0, 3]);
+        assert_eq!(values(&set), [0, 1, 3, 4, 5]);
+        assert!(set.spilled());
+    }
+
+    #[test]
+    fn test_borrowed_lookup_and_range() {
+        let set: SortedSmallSet<String, 2> = ["pear", "apple", "fig", "kiwi"].into_iter().map(String::from).collect();
+        assert!(set.contains("fig"));
+        assert_eq!(set.get("kiwi").map(String::as_str), Some("kiwi"));
+
+        let range = set.range::<str, _>((Bound::Included("b"), Bound::Excluded("l"))).map(String::as_str).collect::<Vec<_>>();
+        assert_eq!(range, ["fig", "kiwi"]);
+    }
+
+    #[test]
+    fn test_merge() {
+        let left: TestSet = [1, 2, 4].into_iter().collect();
+        let right: SortedSmallSet<i32, 1> = [2, 3, 4, 6].into_iter().collect();
+
+        let merged = left.merge(&right).collect::<Vec<_>>();
+        assert_eq!(merged, [
+            Merged::Left(&1),
+            Merged::Both(&2, &2),
+            Merged::Right(&3),
+            Merged::Both(&4, &4),
+            Merged::Right(&6),
+        ]);
+
+        let union = left.merge(&right).map(Merged::into_inner).copied().collect::<Vec<_>>();
+        assert_eq!(union, [1, 2, 3, 4, 6]);
+    }
+}
@@ -7,7 +7,9 @@
 #![allow(clippy::doc_lazy_continuation)]
 
 mod any_matches_macro;
+mod bounded_fixed;
 mod bounded_floats;
+mod bounded_ints;
 mod clamp01;
 mod remove_many;
 mod take_first;
@@ -26,6 +28,21 @@ mod collections;
 #[cfg(feature = "rand")]
 mod random_utils;
 
+#[doc(hidden)]
+pub use float_cmp;
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+pub use proptest;
+#[cfg(feature = "quickcheck")]
+#[doc(hidden)]
+pub use quickcheck;
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+pub use rand;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
+
 pub mod prelude {
     pub use std::{
         any::{type_name, type_name_of_val},
@@ -38,8 +55,11 @@ pub mod prelude {
     };
 
     pub use anyhow::{Result, anyhow, bail};
+    pub use float_cmp::{ApproxEq, F32Margin, F64Margin};
     pub use pluck::pluck;
 
+    #[cfg(feature = "rand")]
+    pub use super::random_utils::bounded::*;
     #[cfg(feature = "rand")]
     pub use super::random_utils::get_random::*;
     #[cfg(feature = "rand")]
@@ -49,8 +69,22 @@ pub mod prelude {
     pub use super::{
         all_matches,
         any_matches,
+        bound_f32,
+        bound_f32_f64_operands,
         bound_f32_impl,
+        bound_f64,
         bound_f64_impl,
+        bound_fixed,
+        bound_fixed_impl,
+        bound_float_interop,
+        bound_float_pair,
+        bound_float_serde,
+        bound_int,
+        bound_int_impl,
+        bounded,
+        bounded_fixed::*,
+        bounded_floats::*,
+        bounded_ints::*,
         clamp01::*,
         closure_converters::*,
         collections::*,
@@ -0,0 +1,112 @@
+use rand::{
+    Rng,
+    RngExt,
+    distr::uniform::{Error, SampleBorrow, SampleUniform, UniformSampler},
+};
+
+use crate::prelude::{BoundedFloat, Probability};
+
+/// Samples a bounded float uniformly over `MIN..=MAX`,
+/// what `rng.random::<B>()` does for types declared with [`bound_f32!`](crate::prelude::bound_f32)
+/// or [`bound_f64!`](crate::prelude::bound_f64).
+pub fn random_bounded<B: BoundedFloat, R: Rng + ?Sized>(rng: &mut R) -> B
+where B::Float: SampleUniform + PartialOrd {
+    B::new(rng.random_range(B::MIN..=B::MAX))
+}
+
+/// The [`UniformSampler`] of bounded floats, which lets `rng.random_range(low..high)` take bounded values.
+pub struct UniformBounded<B: BoundedFloat>(<B::Float as SampleUniform>::Sampler)
+where B::Float: SampleUniform;
+
+impl<B: BoundedFloat> UniformSampler for UniformBounded<B>
+where B::Float: SampleUniform
+{
+    type X = B;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<B> + Sized,
+        B2: SampleBorrow<B> + Sized,
+    {
+        UniformSampler::new(low.borrow().get(), high.borrow().get()).map(UniformBounded)
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<B> + Sized,
+        B2: SampleBorrow<B> + Sized,
+    {
+        UniformSampler::new_inclusive(low.borrow().get(), high.borrow().get()).map(UniformBounded)
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> B { B::new(self.0.sample(rng)) }
+}
+
+impl Probability {
+    /// Returns `true` with this probability.
+    pub fn roll(self, rng: &mut impl Rng) -> bool { rng.random_bool(self.get()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{RngExt, SeedableRng, rngs::StdRng};
+
+    use crate::prelude::*;
+
+    bound_f64! {
+        struct Volume(0.0..=1.0);
+        struct Angle(0.0..=360.0, overflow = Wrap);
+    }
+
+    bound_f32! {
+        struct Gain(-60.0..=12.0);
+    }
+
+    #[test]
+    fn samples_within_bounds() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..1000 {
+            let volume = rng.random::<Volume>();
+            assert!((0.0..=1.0).contains(&volume.get()));
+
+            let gain: Gain = rng.random();
+            assert!((-60.0..=12.0).contains(&gain.get()));
+
+            let angle = random_bounded::<Angle, _>(&mut rng);
+            assert!((0.0..360.0).contains(&angle.get()));
+        }
+    }
+
+    #[test]
+    fn samples_whole_range() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let samples = (0..1000).map(|_| rng.random::<Percent>().get()).collect::<Vec<_>>();
+        assert!(samples.iter().any(|percent| *percent < 10.0));
+        assert!(samples.iter().any(|percent| *percent > 90.0));
+    }
+
+    #[test]
+    fn samples_between_bounded_values() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let (low, high) = (Volume::new(0.25), Volume::new(0.5));
+        for _ in 0..1000 {
+            let volume = rng.random_range(low..high);
+            assert!(volume >= low && volume < high);
+
+            let gain = rng.random_range(Gain::new(-6.0)..=Gain::new(0.0));
+            assert!((-6.0..=0.0).contains(&gain.get()));
+        }
+
+        assert_eq!(rng.random_range(high..=high), high);
+    }
+
+    #[test]
+    fn rolls_probability() {
+        let mut rng = StdRng::seed_from_u64(7);
+        assert!(Probability::new(1.0).roll(&mut rng));
+        assert!(!Probability::new(0.0).roll(&mut rng));
+
+        let hits = (0..1000).filter(|_| Probability::new(0.5).roll(&mut rng)).count();
+        assert!((400..600).contains(&hits));
+    }
+}
@@ -1,3 +1,4 @@
+pub mod bounded;
 pub mod take_random;
 pub mod get_random;
 pub mod weighted_random;